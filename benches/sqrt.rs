@@ -1,7 +1,7 @@
 use ark_ff::{Field, Zero};
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use decaf377::Fq;
-use decaf377::ZETA;
+use decaf377_plus::Fq;
+use decaf377_plus::ZETA;
 use rand_chacha::ChaChaRng;
 use rand_core::{RngCore, SeedableRng};
 
@@ -1,9 +1,9 @@
 use once_cell::sync::Lazy;
 
-use crate::{Fq, Fr};
+use crate::Fq;
 use ark_ff::{self, BigInteger256, Field};
 
-use ark_ed_on_bls12_377::{Fq as ArkFq, Fr as ArkFr};
+use ark_ed_on_bls12_377::Fq as ArkFq;
 
 pub static ONE: Lazy<Fq> = Lazy::new(|| Fq::ONE);
 pub static TWO: Lazy<Fq> = Lazy::new(|| Fq::ONE + Fq::ONE);
@@ -12,10 +12,6 @@ pub(crate) fn from_ark_fq(x: ArkFq) -> Fq {
     BigInteger256::from(x).into()
 }
 
-fn from_ark_fr(x: ArkFr) -> Fr {
-    BigInteger256::from(x).into()
-}
-
 // Zeta is called QNR in the sage specification.
 pub const ZETA: Fq = Fq::from_montgomery_limbs([
     5947794125541564500,
@@ -91,10 +87,3 @@ pub const GENERATOR_Y: Fq = Fq::from_montgomery_limbs([
     10983305067350511165,
     1251302644532346138,
 ]);
-
-// Modulus of basefield
-pub static R: Lazy<Fr> = Lazy::new(|| {
-    from_ark_fr(ark_ff::MontFp!(
-        "2111115437357092606062206234695386632838870926408408195193685246394721360383"
-    ))
-});
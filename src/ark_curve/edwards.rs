@@ -17,6 +17,9 @@ pub struct Decaf377EdwardsConfig;
 pub type EdwardsAffine = Affine<Decaf377EdwardsConfig>;
 pub type EdwardsProjective = Projective<Decaf377EdwardsConfig>;
 
+/// The curve parameter `d`, for code shared with the minimal curve backend.
+pub(crate) const COEFF_D: Fq = <Decaf377EdwardsConfig as TECurveConfig>::COEFF_D;
+
 impl CurveConfig for Decaf377EdwardsConfig {
     type BaseField = Fq;
     type ScalarField = Fr;
@@ -1,22 +1,31 @@
 use crate::{
     Fq, Fr,
-    ark_curve::{Decaf377EdwardsConfig, EdwardsProjective, edwards::EdwardsAffine},
+    ark_curve::{
+        Decaf377EdwardsConfig, EdwardsProjective, edwards::EdwardsAffine, on_curve::OnCurve,
+    },
 };
 use ark_ec::{AffineRepr, CurveGroup, Group, ScalarMul, VariableBaseMSM};
 use ark_serialize::Valid;
 use ark_std::vec::Vec;
 
 pub mod affine;
-#[cfg(feature = "ecc-group")]
-mod ecc;
 pub mod projective;
 
 pub use affine::AffinePoint;
 pub use projective::Element;
 
 impl Valid for Element {
+    /// Checks that this is a point on the curve in the subgroup of order
+    /// `2r`, whose points are the representatives of decaf377 elements.
+    ///
+    /// Elements built by this crate always pass, but points read from
+    /// uncompressed coordinates without validation may not.
     fn check(&self) -> Result<(), ark_serialize::SerializationError> {
-        Ok(())
+        if self.inner.is_on_curve() {
+            Ok(())
+        } else {
+            Err(ark_serialize::SerializationError::InvalidData)
+        }
     }
 }
 
@@ -88,7 +97,7 @@ impl CurveGroup for Element {
 
 impl Valid for AffinePoint {
     fn check(&self) -> Result<(), ark_serialize::SerializationError> {
-        Ok(())
+        Element::from(self).check()
     }
 }
 
@@ -166,29 +175,3 @@ impl From<&AffinePoint> for Element {
         }
     }
 }
-
-#[cfg(feature = "serde")]
-impl serdect::serde::Serialize for Element {
-    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
-    where
-        S: serdect::serde::Serializer,
-    {
-        use elliptic_curve::group::GroupEncoding;
-        let bytes = self.to_bytes();
-        serdect::array::serialize_hex_lower_or_bin(&bytes, s)
-    }
-}
-
-#[cfg(feature = "serde")]
-impl<'de> serdect::serde::Deserialize<'de> for Element {
-    fn deserialize<D>(d: D) -> Result<Self, D::Error>
-    where
-        D: serdect::serde::Deserializer<'de>,
-    {
-        use elliptic_curve::group::GroupEncoding;
-        let mut bytes = [0u8; 32];
-        let _ = serdect::array::deserialize_hex_or_bin::<D>(&mut bytes, d)?;
-        Option::from(Element::from_bytes(&bytes))
-            .ok_or(serdect::serde::de::Error::custom("Invalid encoding"))
-    }
-}
@@ -16,7 +16,9 @@ pub struct AffinePoint {
 
 impl Hash for AffinePoint {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.inner.hash(state);
+        // Equal points can differ by 2-torsion, so hash the canonical
+        // encoding to stay consistent with `PartialEq`.
+        Element::from(self).vartime_compress().0.hash(state);
     }
 }
 
@@ -1,16 +1,12 @@
 use ark_ec::CurveGroup;
 use ark_ff::Zero;
-use ark_serialize::CanonicalSerialize;
 use ark_std::fmt::{Display, Formatter, Result as FmtResult};
-use core::borrow::Borrow;
 use core::hash::Hash;
-#[cfg(features = "ecc-group")]
-use elliptic_curve::group::GroupEncoding;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use zeroize::Zeroize;
 
-use crate::{Fq, Fr, ark_curve::EdwardsProjective};
+use crate::{Fq, ark_curve::EdwardsProjective};
 
 use super::super::constants::{B_T, B_X, B_Y, B_Z};
 
@@ -32,7 +28,22 @@ impl Element {
 
 impl Hash for Element {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.inner.hash(state);
+        // Equal elements can have different projective coordinates, so hash
+        // the canonical encoding to stay consistent with `PartialEq`.
+        self.vartime_compress().0.hash(state);
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    /// Orders elements by their canonical encodings.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.vartime_compress().cmp(&other.vartime_compress())
     }
 }
 
@@ -89,35 +100,11 @@ impl Element {
         self.inner.x == Fq::zero()
     }
 
-    /// Given an iterator of public scalars and an iterator of public points,
-    /// compute
-    /// $$
-    /// Q = \[c\_1\] P\_1 + \cdots + \[c\_n\] P\_n,
-    /// $$
-    /// using variable-time operations.
-    ///
-    /// It is an error to call this function with two iterators of different
-    /// lengths -- it would require `ExactSizeIterator`, but
-    /// `ExactSizeIterator`s are not closed under chaining, and disallowing
-    /// iterator chaining would destroy the utility of the function.
-    pub fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Element
-    where
-        I: IntoIterator,
-        I::Item: Borrow<Fr>,
-        J: IntoIterator,
-        J::Item: Borrow<Element>,
-    {
-        // XXX this is a stub implementation, try to use a real MSM later
-        let scalars = scalars.into_iter();
-        let points = points.into_iter();
-
-        // XXX panic on length mismatches ? or error?
-
-        scalars
-            .zip(points)
-            .fold(Element::default(), |acc, (scalar, point)| {
-                acc + (scalar.borrow() * point.borrow())
-            })
+    /// Returns `self + self`.
+    pub fn double(&self) -> Element {
+        Element {
+            inner: ark_ec::Group::double(&self.inner),
+        }
     }
 }
 
@@ -156,14 +143,59 @@ impl ConditionallySelectable for Element {
 
 impl ConstantTimeEq for Element {
     fn ct_eq(&self, other: &Self) -> Choice {
-        let mut lhs_bytes = [0u8; 32];
-        self.serialize_compressed(&mut lhs_bytes[..])
-            .expect("serialization to succeed");
-        let mut rhs_bytes = [0u8; 32];
-        other
-            .serialize_compressed(&mut rhs_bytes[..])
-            .expect("serialization to succeed");
-
-        lhs_bytes.ct_eq(&rhs_bytes)
+        // Section 4.5 of Decaf paper
+        (self.inner.x * other.inner.y).ct_eq(&(self.inner.y * other.inner.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hashbrown::HashSet;
+
+    use crate::Fr;
+
+    /// Returns elements equal to `point`, but with different coordinates.
+    fn other_representatives(point: &Element) -> [Element; 2] {
+        let p = &point.inner;
+
+        // Scaling the projective coordinates.
+        let lambda = Fq::from(7u64);
+        let scaled = EdwardsProjective::new_unchecked(
+            lambda * p.x,
+            lambda * p.y,
+            lambda * p.t,
+            lambda * p.z,
+        );
+
+        // Adding the 2-torsion point (0, -1).
+        let torsion = EdwardsProjective::new_unchecked(-p.x, -p.y, p.t, p.z);
+
+        [Element { inner: scaled }, Element { inner: torsion }]
+    }
+
+    #[test]
+    fn equal_elements_hash_and_order_equally() {
+        let point = Element::GENERATOR * Fr::from(5u64);
+        for other in other_representatives(&point) {
+            assert_eq!(point, other);
+            assert_ne!(point.inner.x, other.inner.x);
+            assert_eq!(point.cmp(&other), core::cmp::Ordering::Equal);
+
+            let mut set = HashSet::new();
+            set.insert(point);
+            assert!(set.contains(&other));
+            set.insert(other);
+            assert_eq!(set.len(), 1);
+        }
+    }
+
+    #[test]
+    fn ordering_matches_encoding_ordering() {
+        let a = Element::GENERATOR;
+        let b = Element::GENERATOR.double();
+        assert_eq!(a.cmp(&b), a.vartime_compress().cmp(&b.vartime_compress()));
+        assert!(Element::IDENTITY < a);
     }
 }
@@ -1,12 +1,16 @@
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::convert::{TryFrom, TryInto};
 
 use ark_ec::twisted_edwards::TECurveConfig;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Read, Write};
+use subtle::{ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::ark_curve::{
-    EdwardsProjective, Element, constants::TWO, edwards::Decaf377EdwardsConfig, on_curve::OnCurve,
+    AffinePoint, EdwardsProjective, Element, constants::TWO, edwards::Decaf377EdwardsConfig,
+    on_curve::OnCurve,
 };
 use crate::sign::Sign;
 use crate::{EncodingError, Fq};
@@ -30,22 +34,42 @@ impl Encoding {
     }
 
     pub fn vartime_decompress(&self) -> Result<Element, EncodingError> {
+        let (s, invsqrt_input) = self.vartime_invsqrt_input()?;
+
+        // 5. sqrt
+        let (was_square, v) = Fq::sqrt_ratio_zeta(&Fq::ONE, &invsqrt_input);
+        if !was_square {
+            return Err(EncodingError::NotASquare);
+        }
+
+        Ok(Encoding::decompress_with_invsqrt(&s, v))
+    }
+
+    /// Checks this encoding and returns its field element `s` along with
+    /// `u_2 u_1^2`, whose inverse square root is the one square root the
+    /// decoding needs; the encoding is valid if and only if that is square.
+    pub(crate) fn vartime_invsqrt_input(&self) -> Result<(Fq, Fq), EncodingError> {
         // Top three bits of last byte should be zero
         if self.0[31] >> 5 != 0u8 {
-            return Err(EncodingError::InvalidEncoding);
+            return Err(EncodingError::NonZeroHighBits);
         }
 
-        // This isn't a constant, only because traits don't have const methods
-        // yet and multiplication is only implemented as part of the Mul trait.
-        let D4: Fq = Decaf377EdwardsConfig::COEFF_D * Fq::from(4u32);
-
         // 1/2. Reject unless s is canonically encoded and nonnegative.
-        let s =
-            Fq::deserialize_compressed(&self.0[..]).map_err(|_| EncodingError::InvalidEncoding)?;
+        let s = Fq::from_bytes_checked(&self.0)?;
         if s.is_negative() {
-            return Err(EncodingError::InvalidEncoding);
+            return Err(EncodingError::NegativeS);
         }
 
+        let (u_1, u_2) = Encoding::decompress_u(&s);
+        Ok((s, u_2 * u_1.square()))
+    }
+
+    /// Computes `u_1 = 1 - s^2` and `u_2 = u_1^2 - 4d s^2`.
+    fn decompress_u(s: &Fq) -> (Fq, Fq) {
+        // This isn't a constant, only because traits don't have const methods
+        // yet and multiplication is only implemented as part of the Mul trait.
+        let D4: Fq = Decaf377EdwardsConfig::COEFF_D * Fq::from(4u32);
+
         // 3. u_1 <- 1 - s^2
         let ss = s.square();
         let u_1 = Fq::ONE - ss;
@@ -53,11 +77,14 @@ impl Encoding {
         // 4. u_2 <- u_1^2 - 4d s^2
         let u_2 = u_1.square() - D4 * ss;
 
-        // 5. sqrt
-        let (was_square, mut v) = Fq::sqrt_ratio_zeta(&Fq::ONE, &(u_2 * u_1.square()));
-        if !was_square {
-            return Err(EncodingError::InvalidEncoding);
-        }
+        (u_1, u_2)
+    }
+
+    /// Finishes decoding the element with field element `s`, given the
+    /// inverse square root `v` of the second value of
+    /// [`Encoding::vartime_invsqrt_input`], in either sign.
+    pub(crate) fn decompress_with_invsqrt(s: &Fq, mut v: Fq) -> Element {
+        let (u_1, u_2) = Encoding::decompress_u(s);
 
         // 6. sign check
         let two_s_u_1 = *TWO * s * u_1;
@@ -68,7 +95,7 @@ impl Encoding {
 
         // 7. coordinates
         let x = two_s_u_1 * v.square() * u_2;
-        let y = (Fq::ONE + ss) * v * u_1;
+        let y = (Fq::ONE + s.square()) * v * u_1;
         let z = Fq::ONE;
         let t = x * y;
 
@@ -77,9 +104,56 @@ impl Encoding {
             "resulting point must be on the curve",
         );
 
-        Ok(Element {
+        Element {
             inner: EdwardsProjective::new(x, y, t, z),
-        })
+        }
+    }
+}
+
+impl Encoding {
+    /// Decodes this encoding in constant time, returning `None` if it is not
+    /// the canonical encoding of a decaf377 element.
+    ///
+    /// Unlike [`Encoding::vartime_decompress`], this does not branch on the
+    /// encoding, so it is suitable for decoding secret points.
+    pub fn decompress_ct(&self) -> CtOption<Element> {
+        let D4: Fq = Decaf377EdwardsConfig::COEFF_D * Fq::from(4u32);
+
+        // Top three bits of last byte should be zero
+        let high_bits_clear = (self.0[31] >> 5).ct_eq(&0u8);
+
+        // 1/2. Reject unless s is canonically encoded and nonnegative.
+        let s = Fq::from_raw_bytes(&self.0);
+        let s_is_canonical = s.to_bytes_le().ct_eq(&self.0);
+        let s_is_nonnegative = !s.ct_is_negative();
+
+        // 3. u_1 <- 1 - s^2
+        let ss = s.square();
+        let u_1 = Fq::ONE - ss;
+
+        // 4. u_2 <- u_1^2 - 4d s^2
+        let u_2 = u_1.square() - D4 * ss;
+
+        // 5. sqrt
+        let (was_square, v) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &(u_2 * u_1.square()));
+
+        // 6. sign check
+        let two_s_u_1 = *TWO * s * u_1;
+        let check = two_s_u_1 * v;
+        let v = Fq::conditional_select(&v, &-v, check.ct_is_negative());
+
+        // 7. coordinates
+        let x = two_s_u_1 * v.square() * u_2;
+        let y = (Fq::ONE + ss) * v * u_1;
+        let z = Fq::ONE;
+        let t = x * y;
+
+        CtOption::new(
+            Element {
+                inner: EdwardsProjective::new_unchecked(x, y, t, z),
+            },
+            high_bits_clear & s_is_canonical & s_is_nonnegative & was_square,
+        )
     }
 }
 
@@ -89,6 +163,28 @@ impl Element {
     }
 
     pub fn vartime_compress_to_field(&self) -> Fq {
+        // 2. division by 0 occurs on the identity point, but since
+        // sqrt_ratio_zeta outputs v=0 it computes the right encoding anyway
+        let (_always_square, v) = Fq::sqrt_ratio_zeta(&Fq::ONE, &self.invsqrt_input());
+
+        self.compress_with_invsqrt(&v)
+    }
+
+    /// Returns `u_1 (a - d) X^2`, whose inverse square root `v` is the one
+    /// square root the encoding needs.
+    pub(crate) fn invsqrt_input(&self) -> Fq {
+        let A_MINUS_D = Decaf377EdwardsConfig::COEFF_A - Decaf377EdwardsConfig::COEFF_D;
+        let p = &self.inner;
+
+        // 1.
+        let u_1 = (p.x + p.t) * (p.x - p.t);
+
+        u_1 * A_MINUS_D * p.x.square()
+    }
+
+    /// Finishes the encoding to the field element `s`, given the inverse
+    /// square root `v` of [`Element::invsqrt_input`], in either sign.
+    pub(crate) fn compress_with_invsqrt(&self, v: &Fq) -> Fq {
         // This isn't a constant, only because traits don't have const methods
         // yet and subtraction is only implemented as part of the Sub trait.
         let A_MINUS_D = Decaf377EdwardsConfig::COEFF_A - Decaf377EdwardsConfig::COEFF_D;
@@ -97,20 +193,14 @@ impl Element {
         // 1.
         let u_1 = (p.x + p.t) * (p.x - p.t);
 
-        // 2. division by 0 occurs on the identity point, but since
-        // sqrt_ratio_zeta outputs v=0 it computes the right encoding anyway
-        let (_always_square, v) = Fq::sqrt_ratio_zeta(&Fq::ONE, &(u_1 * A_MINUS_D * p.x.square()));
-
         // 3.
-        let u_2 = (v * u_1).abs();
+        let u_2 = (*v * u_1).abs();
 
         // 4.
         let u_3 = u_2 * p.z - p.t;
 
         // 5.
-        let s = (A_MINUS_D * v * u_3 * p.x).abs();
-
-        s
+        (A_MINUS_D * v * u_3 * p.x).abs()
     }
 
     pub fn vartime_compress(&self) -> Encoding {
@@ -128,6 +218,99 @@ impl Element {
     }
 }
 
+impl Element {
+    /// Encodes this element in constant time.
+    ///
+    /// This produces the same encoding as [`Element::vartime_compress`], but
+    /// does not branch on the element, so it is suitable for encoding secret
+    /// points.
+    pub fn compress(&self) -> Encoding {
+        let A_MINUS_D = Decaf377EdwardsConfig::COEFF_A - Decaf377EdwardsConfig::COEFF_D;
+        let p = &self.inner;
+
+        // 1.
+        let u_1 = (p.x + p.t) * (p.x - p.t);
+
+        // 2. division by 0 occurs on the identity point, but since
+        // sqrt_ratio_zeta_ct outputs v=0 it computes the right encoding anyway
+        let (_always_square, v) =
+            Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &(u_1 * A_MINUS_D * p.x.square()));
+
+        // 3.
+        let u_2 = (v * u_1).ct_abs();
+
+        // 4.
+        let u_3 = u_2 * p.z - p.t;
+
+        // 5.
+        let s = (A_MINUS_D * v * u_3 * p.x).ct_abs();
+
+        Encoding(s.to_bytes_le())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Element {
+    /// Encodes `[2]P` for every element `P` in `points`, using a single
+    /// field inversion for the whole batch and no square roots.
+    ///
+    /// For a doubled point, the inverse square root needed by the encoding
+    /// can be read off the doubling formulas, so the expensive part of
+    /// compression is replaced by a batch inversion. This is the decaf377
+    /// analogue of Ristretto's `double_and_compress_batch`.
+    ///
+    /// This is variable-time, and should only be used on public points.
+    pub fn double_and_compress_batch(points: &[Element]) -> Vec<Encoding> {
+        let A_MINUS_D = Decaf377EdwardsConfig::COEFF_A - Decaf377EdwardsConfig::COEFF_D;
+        let ONE_PLUS_D = Fq::ONE + Decaf377EdwardsConfig::COEFF_D;
+
+        // Doubling with the a = -1 formulas of https://eprint.iacr.org/2008/522
+        // gives 2P = (E F : G H : F G : E H), in (X : Y : Z : T)
+        // coordinates. Substituting into step 2 of the
+        // encoding, the square root of u_1 (a - d) X^2 is (1 + d) E^3 F, so
+        // only its inverse needs to be computed.
+        let states: Vec<_> = points
+            .iter()
+            .map(|point| {
+                let p = &point.inner;
+                let a = p.x.square();
+                let b = p.y.square();
+                let c = *TWO * p.z.square();
+                let e = *TWO * p.x * p.y;
+                let g = b - a;
+                let f = g - c;
+                let h = -a - b;
+                (e * f, f * g, e * h, ONE_PLUS_D * e.square() * e * f)
+            })
+            .collect();
+
+        let mut invsqrts: Vec<Fq> = states.iter().map(|state| state.3).collect();
+        Fq::batch_inverse(&mut invsqrts);
+
+        states
+            .iter()
+            .zip(invsqrts)
+            .map(|(&(x, z, t, _), v)| {
+                // 1.
+                let u_1 = (x + t) * (x - t);
+
+                // 2. v = 0 on the identity point, which gives s = 0 below.
+
+                // 3.
+                let u_2 = (v * u_1).abs();
+
+                // 4.
+                let u_3 = u_2 * z - t;
+
+                // 5.
+                let s = (A_MINUS_D * v * u_3 * x).abs();
+
+                Encoding(s.to_bytes_le())
+            })
+            .collect()
+    }
+}
+
 impl From<&Element> for Encoding {
     fn from(point: &Element) -> Self {
         point.vartime_compress()
@@ -141,11 +324,10 @@ impl From<Element> for Encoding {
 }
 
 impl CanonicalSerialize for Encoding {
-    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
-        match compress {
-            ark_serialize::Compress::Yes => 32,
-            ark_serialize::Compress::No => unimplemented!(),
-        }
+    /// An encoding is already a byte string, so it has the same 32-byte
+    /// representation in both modes.
+    fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+        32
     }
 
     fn serialize_with_mode<W: Write>(
@@ -159,10 +341,12 @@ impl CanonicalSerialize for Encoding {
 }
 
 impl CanonicalSerialize for Element {
+    /// Compressed elements use their 32-byte encoding, and uncompressed
+    /// elements use the affine Edwards coordinates of a representative.
     fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
         match compress {
             ark_serialize::Compress::Yes => 32,
-            ark_serialize::Compress::No => unimplemented!(),
+            ark_serialize::Compress::No => 64,
         }
     }
 
@@ -171,7 +355,14 @@ impl CanonicalSerialize for Element {
         writer: W,
         mode: ark_serialize::Compress,
     ) -> Result<(), ark_serialize::SerializationError> {
-        self.vartime_compress().serialize_with_mode(writer, mode)
+        match mode {
+            ark_serialize::Compress::Yes => {
+                self.vartime_compress().serialize_with_mode(writer, mode)
+            }
+            ark_serialize::Compress::No => {
+                AffinePoint::from(self).serialize_with_mode(writer, mode)
+            }
+        }
     }
 }
 
@@ -253,17 +444,9 @@ impl ark_serialize::Valid for Encoding {
 impl CanonicalDeserialize for Encoding {
     fn deserialize_with_mode<R: Read>(
         mut reader: R,
-        compress: ark_serialize::Compress,
-        validate: ark_serialize::Validate,
+        _compress: ark_serialize::Compress,
+        _validate: ark_serialize::Validate,
     ) -> Result<Self, ark_serialize::SerializationError> {
-        match compress {
-            ark_serialize::Compress::Yes => (),
-            ark_serialize::Compress::No => unimplemented!(),
-        }
-        match validate {
-            ark_serialize::Validate::Yes => (),
-            ark_serialize::Validate::No => unimplemented!(),
-        }
         let mut bytes = [0u8; 32];
         reader.read_exact(&mut bytes[..])?;
         Ok(Self(bytes))
@@ -277,16 +460,15 @@ impl CanonicalDeserialize for Element {
         validate: ark_serialize::Validate,
     ) -> Result<Self, ark_serialize::SerializationError> {
         match compress {
-            ark_serialize::Compress::Yes => (),
-            ark_serialize::Compress::No => unimplemented!(),
-        }
-        match validate {
-            ark_serialize::Validate::Yes => (),
-            ark_serialize::Validate::No => unimplemented!(),
+            // Decompression has to check the encoding to find the point at
+            // all, so the result is valid whatever `validate` is.
+            ark_serialize::Compress::Yes => {
+                let bytes = Encoding::deserialize_compressed(reader)?;
+                Ok(bytes.vartime_decompress()?)
+            }
+            ark_serialize::Compress::No => {
+                AffinePoint::deserialize_with_mode(reader, compress, validate).map(Element::from)
+            }
         }
-        let bytes = Encoding::deserialize_compressed(reader)?;
-        bytes
-            .try_into()
-            .map_err(|_| ark_serialize::SerializationError::InvalidData)
     }
 }
@@ -85,9 +85,15 @@ impl Fq {
             return (false, *den);
         }
 
-        let s_exp: BigInteger256 = (2u64.pow(N) - 1).into();
-        let s = den.pow(s_exp);
-        let t = s.square() * den;
+        // Dividing by one is free, which batched callers rely on after
+        // inverting their denominators together.
+        let (s, t) = if *den == Fq::ONE {
+            (Fq::ONE, Fq::ONE)
+        } else {
+            let s_exp: BigInteger256 = (2u64.pow(N) - 1).into();
+            let s = den.pow(s_exp);
+            (s, s.square() * den)
+        };
         let w = (*num * t).pow(*M_MINUS_ONE_DIV_TWO) * s;
 
         let v = w * den;
@@ -11,6 +11,7 @@ pub mod rand;
 pub mod serialize;
 
 pub use constants::ZETA;
+pub(crate) use edwards::COEFF_D;
 pub(crate) use edwards::{Decaf377EdwardsConfig, EdwardsProjective};
 pub use element::{AffinePoint, Element};
 pub use encoding::Encoding;
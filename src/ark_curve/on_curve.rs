@@ -2,10 +2,9 @@ use ark_ec::{
     Group,
     models::{twisted_edwards::Projective, twisted_edwards::TECurveConfig},
 };
-use ark_ff::{BigInteger, Field, PrimeField, Zero};
-use ark_serialize::CanonicalSerialize;
+use ark_ff::{BigInteger, Field, Zero};
 
-use crate::ark_curve::constants;
+use crate::Fr;
 
 pub trait OnCurve {
     fn is_on_curve(&self) -> bool;
@@ -24,12 +23,9 @@ impl<P: TECurveConfig> OnCurve for Projective<P> {
         let on_segre_embedding = self.t * self.z == self.x * self.y;
         let z_non_zero = self.z != P::BaseField::zero();
         let point_order_2r = {
-            let mut r_bytes = [0u8; 32];
-            (*constants::R)
-                .serialize_compressed(&mut r_bytes[..])
-                .expect("serialization into array should be infallible");
-            let r = P::ScalarField::from_le_bytes_mod_order(&r_bytes);
-            let mut two_r_bigint = r.into_bigint();
+            // Reducing r into the scalar field would give zero, so build 2r
+            // from the modulus limbs directly.
+            let mut two_r_bigint = ark_ff::BigInt(Fr::MODULUS_LIMBS);
             two_r_bigint.mul2();
             self.mul_bigint(two_r_bigint) == Projective::zero()
         };
@@ -1,46 +1,60 @@
-use core::convert::TryInto;
-
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Valid};
 use ark_std::io::{Read, Write};
 
-use crate::ark_curve::{AffinePoint, Element, Encoding};
+use crate::Fq;
+use crate::ark_curve::{AffinePoint, Element, Encoding, edwards::EdwardsAffine};
 
 impl CanonicalDeserialize for AffinePoint {
     fn deserialize_with_mode<R: Read>(
-        reader: R,
+        mut reader: R,
         compress: ark_serialize::Compress,
         validate: ark_serialize::Validate,
     ) -> Result<Self, ark_serialize::SerializationError> {
         match compress {
-            ark_serialize::Compress::Yes => (),
-            ark_serialize::Compress::No => unimplemented!(),
-        };
-        match validate {
-            ark_serialize::Validate::Yes => (),
-            ark_serialize::Validate::No => unimplemented!(),
+            ark_serialize::Compress::Yes => {
+                // Decompression always checks the encoding.
+                let bytes = Encoding::deserialize_compressed(reader)?;
+                Ok(bytes.vartime_decompress()?.into())
+            }
+            ark_serialize::Compress::No => {
+                let x = Fq::deserialize_with_mode(&mut reader, compress, validate)?;
+                let y = Fq::deserialize_with_mode(&mut reader, compress, validate)?;
+                let point = AffinePoint {
+                    inner: EdwardsAffine::new_unchecked(x, y),
+                };
+                if let ark_serialize::Validate::Yes = validate {
+                    point.check()?;
+                }
+                Ok(point)
+            }
         }
-        let bytes = Encoding::deserialize_compressed(reader)?;
-        let element: Element = bytes
-            .try_into()
-            .map_err(|_| ark_serialize::SerializationError::InvalidData)?;
-        Ok(element.into())
     }
 }
 
 impl CanonicalSerialize for AffinePoint {
+    /// Compressed points use the 32-byte encoding of their element, and
+    /// uncompressed points use their affine coordinates `x` and `y`.
     fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
         match compress {
             ark_serialize::Compress::Yes => 32,
-            ark_serialize::Compress::No => unimplemented!(),
+            ark_serialize::Compress::No => 64,
         }
     }
 
     fn serialize_with_mode<W: Write>(
         &self,
-        writer: W,
+        mut writer: W,
         mode: ark_serialize::Compress,
     ) -> Result<(), ark_serialize::SerializationError> {
-        let element: Element = self.into();
-        element.vartime_compress().serialize_with_mode(writer, mode)
+        match mode {
+            ark_serialize::Compress::Yes => {
+                let element: Element = self.into();
+                element.vartime_compress().serialize_with_mode(writer, mode)
+            }
+            ark_serialize::Compress::No => {
+                self.inner.x.serialize_with_mode(&mut writer, mode)?;
+                self.inner.y.serialize_with_mode(&mut writer, mode)
+            }
+        }
     }
 }
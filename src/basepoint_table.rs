@@ -0,0 +1,120 @@
+//! Precomputed tables for constant-time fixed-base scalar multiplication.
+
+use core::ops::Mul;
+
+use zeroize::Zeroize;
+
+use crate::{Element, Fr, window::LookupTable};
+
+/// A precomputed table of multiples of a basepoint, for fast constant-time
+/// scalar multiplication by a fixed point.
+///
+/// The table holds `[1, 2, ..., 8] * 16^(2i) * B` for `0 <= i < 32`, so that
+/// a scalar written in signed radix 16 can be multiplied in with only four
+/// doublings, at the cost of about 32KB of storage.
+///
+/// A table for [`Element::GENERATOR`] is available through
+/// [`BasepointTable::generator`].
+#[derive(Clone)]
+pub struct BasepointTable([LookupTable; 32]);
+
+impl BasepointTable {
+    /// Builds the table of multiples of `basepoint`.
+    pub fn create(basepoint: &Element) -> BasepointTable {
+        let mut tables = [LookupTable::from(basepoint); 32];
+        let mut point = *basepoint;
+        for table in tables.iter_mut().skip(1) {
+            point = point.mul_by_pow_2(8);
+            *table = LookupTable::from(&point);
+        }
+        BasepointTable(tables)
+    }
+
+    /// Returns the basepoint this table was built from.
+    pub fn basepoint(&self) -> Element {
+        self.0[0].0[0]
+    }
+
+    /// Returns the table for [`Element::GENERATOR`], building it on first
+    /// use.
+    #[cfg(feature = "std")]
+    pub fn generator() -> &'static BasepointTable {
+        static GENERATOR_TABLE: once_cell::sync::Lazy<BasepointTable> =
+            once_cell::sync::Lazy::new(|| BasepointTable::create(&Element::GENERATOR));
+
+        &GENERATOR_TABLE
+    }
+
+    /// Computes `scalar * B` in time independent of `scalar`.
+    pub fn mul(&self, scalar: &Fr) -> Element {
+        // Write the scalar as a = sum_i a_i 16^i, and split the sum into even
+        // and odd indices:
+        //
+        //   a * B = sum_i a_(2i) 16^(2i) B + 16 * sum_i a_(2i+1) 16^(2i) B,
+        //
+        // so that each term is a lookup into one of the tables.
+        let mut digits = scalar.as_radix_16();
+
+        let mut acc = Element::IDENTITY;
+        for (i, table) in self.0.iter().enumerate() {
+            acc += table.select(digits[2 * i + 1]);
+        }
+        acc = acc.mul_by_pow_2(4);
+        for (i, table) in self.0.iter().enumerate() {
+            acc += table.select(digits[2 * i]);
+        }
+
+        digits.zeroize();
+
+        acc
+    }
+}
+
+impl Element {
+    /// Computes `scalar * B` for [`Element::GENERATOR`] in time independent
+    /// of `scalar`.
+    ///
+    /// With the `std` feature this uses the cached
+    /// [`BasepointTable::generator`]; otherwise it falls back to the
+    /// fixed-window ladder, which needs no precomputation.
+    pub(crate) fn basepoint_mul_ct(scalar: &Fr) -> Element {
+        #[cfg(feature = "std")]
+        {
+            BasepointTable::generator().mul(scalar)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Element::GENERATOR.scalar_mul_ct(scalar)
+        }
+    }
+}
+
+impl core::fmt::Debug for BasepointTable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("BasepointTable")
+            .field(&self.basepoint())
+            .finish()
+    }
+}
+
+impl From<&Element> for BasepointTable {
+    fn from(basepoint: &Element) -> Self {
+        BasepointTable::create(basepoint)
+    }
+}
+
+impl Mul<&Fr> for &BasepointTable {
+    type Output = Element;
+
+    fn mul(self, scalar: &Fr) -> Element {
+        BasepointTable::mul(self, scalar)
+    }
+}
+
+impl Mul<&BasepointTable> for &Fr {
+    type Output = Element;
+
+    fn mul(self, table: &BasepointTable) -> Element {
+        table * self
+    }
+}
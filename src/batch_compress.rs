@@ -0,0 +1,71 @@
+//! Encoding many elements at once.
+//!
+//! The backends split the encoding around its one square root, so the same
+//! batching code serves both the arkworks and the minimal curve backends.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Element, Encoding, Fq};
+
+/// Computes the square root of `num`, or of `zeta * num` if it is
+/// nonsquare, with the variable-time square root of the active backend,
+/// returning whether `num` was square.
+///
+/// This is the square root of the ratio `num / 1`, which skips the division,
+/// for batches whose denominators were inverted together.
+pub(crate) fn vartime_sqrt_zeta(num: &Fq) -> (bool, Fq) {
+    #[cfg(feature = "arkworks")]
+    {
+        Fq::sqrt_ratio_zeta(num, &Fq::ONE)
+    }
+    #[cfg(not(feature = "arkworks"))]
+    {
+        Fq::non_arkworks_sqrt_ratio_zeta(num, &Fq::ONE)
+    }
+}
+
+impl Element {
+    /// Encodes a batch of elements, sharing the field inversion of every
+    /// encoding with a single inversion for the whole batch.
+    ///
+    /// Encoding `P` takes the inverse square root of a field element `w`,
+    /// which a lone encoding computes as the square root of the ratio `1/w`.
+    /// Here the `w` of every element are inverted together, so each element
+    /// only needs the square root of a field element, with no division;
+    /// that square root cannot be shared between elements. With the
+    /// `parallel` feature, the square roots are computed in parallel.
+    ///
+    /// The encodings are the same as those of [`Element::vartime_compress`],
+    /// and like it this is variable-time. For points that are doubled
+    /// anyway, [`Element::double_and_compress_batch`] avoids the square
+    /// roots too.
+    pub fn batch_compress(points: &[Element]) -> Vec<Encoding> {
+        let mut invsqrt_inputs: Vec<Fq> = points.iter().map(Element::invsqrt_input).collect();
+        // The identity point has w = 0, which stays zero and gives s = 0.
+        Fq::batch_inverse(&mut invsqrt_inputs);
+
+        let compress = |(point, inverse): (&Element, &Fq)| {
+            // sqrt(1/w) = 1/sqrt(w), which is always square for an element.
+            let (_always_square, v) = vartime_sqrt_zeta(inverse);
+            Encoding(point.compress_with_invsqrt(&v).to_bytes_le())
+        };
+
+        #[cfg(feature = "parallel")]
+        let encodings = points
+            .par_iter()
+            .zip(invsqrt_inputs.par_iter())
+            .map(compress)
+            .collect();
+        #[cfg(not(feature = "parallel"))]
+        let encodings = points
+            .iter()
+            .zip(invsqrt_inputs.iter())
+            .map(compress)
+            .collect();
+
+        encodings
+    }
+}
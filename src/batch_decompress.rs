@@ -0,0 +1,68 @@
+//! Decoding many encodings at once.
+//!
+//! The backends split the decoding around its one square root, so the same
+//! batching code serves both the arkworks and the minimal curve backends.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::batch_compress::vartime_sqrt_zeta;
+use crate::{Element, Encoding, EncodingError, Fq};
+
+impl Encoding {
+    /// Decodes a batch of encodings, returning the index and error of the
+    /// first invalid encoding if any of them fail to decode.
+    ///
+    /// Decoding takes the inverse square root of a field element `w`, which
+    /// a lone decoding computes as the square root of the ratio `1/w`. Here
+    /// the `w` of every encoding are inverted together with a single field
+    /// inversion, so each encoding only needs the square root of a field
+    /// element, with no division; that square root cannot be shared between
+    /// encodings. With the `parallel` feature, the square roots are computed
+    /// in parallel. The reported index is always the lowest failing one,
+    /// whether or not the batch is decoded in parallel.
+    ///
+    /// This is variable-time, like [`Encoding::vartime_decompress`].
+    pub fn batch_vartime_decompress(
+        encodings: &[Encoding],
+    ) -> Result<Vec<Element>, (usize, EncodingError)> {
+        let checked: Vec<_> = encodings
+            .iter()
+            .map(Encoding::vartime_invsqrt_input)
+            .collect();
+
+        // Invalid encodings take no part in the inversion, and a zero w
+        // stays zero, which is rejected below as it is by a lone decoding.
+        let mut inverses: Vec<Fq> = checked
+            .iter()
+            .map(|result| result.map_or(Fq::ZERO, |(_, w)| w))
+            .collect();
+        Fq::batch_inverse(&mut inverses);
+
+        let decompress = |(result, inverse): (&Result<(Fq, Fq), EncodingError>, &Fq)| {
+            let (s, _) = (*result)?;
+            let (was_square, v) = vartime_sqrt_zeta(inverse);
+            if !was_square || *inverse == Fq::ZERO {
+                return Err(EncodingError::NotASquare);
+            }
+            Ok(Encoding::decompress_with_invsqrt(&s, v))
+        };
+
+        #[cfg(feature = "parallel")]
+        let results: Vec<_> = checked
+            .par_iter()
+            .zip(inverses.par_iter())
+            .map(decompress)
+            .collect();
+        #[cfg(not(feature = "parallel"))]
+        let results = checked.iter().zip(inverses.iter()).map(decompress);
+
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(i, result)| result.map_err(|e| (i, e)))
+            .collect()
+    }
+}
@@ -0,0 +1,194 @@
+//! Pedersen commitments to scalars and vectors of scalars.
+//!
+//! A commitment to a value `v` with blinding factor `r` is
+//! `v * B + r * B_blinding`, and a commitment to a vector `v_1, ..., v_n` is
+//! `v_1 * G_1 + ... + v_n * G_n + r * H`. Commitments are hiding as long as
+//! the blinding factor is uniformly random, and binding as long as no
+//! discrete log relation between the generators is known.
+//!
+//! Apart from [`Element::GENERATOR`], the generators are derived with
+//! [`Element::derive_generator`], so nobody knows their discrete logs.
+//!
+//! Commitments are additively homomorphic: the sum of commitments to `v`
+//! and `v'` is a commitment to `v + v'`, with the sum of the blinding
+//! factors.
+
+#![allow(non_snake_case)]
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{Element, Encoding, EncodingError, Fr, MultiscalarMulError};
+
+/// Domain separator for the generators of [`PedersenGens`].
+const DOMAIN: &[u8] = b"decaf377-pedersen";
+
+/// Domain separator for the generators of [`VectorPedersenGens`].
+const VECTOR_DOMAIN: &[u8] = b"decaf377-pedersen-vector";
+
+/// A Pedersen commitment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Commitment(pub Element);
+
+impl Commitment {
+    /// Returns the encoding of this commitment.
+    pub fn to_encoding(&self) -> Encoding {
+        self.0.vartime_compress()
+    }
+}
+
+impl Add for Commitment {
+    type Output = Commitment;
+
+    fn add(self, rhs: Commitment) -> Commitment {
+        Commitment(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Commitment {
+    fn add_assign(&mut self, rhs: Commitment) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Commitment {
+    type Output = Commitment;
+
+    fn sub(self, rhs: Commitment) -> Commitment {
+        Commitment(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Commitment {
+    fn sub_assign(&mut self, rhs: Commitment) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Commitment {
+    type Output = Commitment;
+
+    fn neg(self) -> Commitment {
+        Commitment(-self.0)
+    }
+}
+
+impl From<Commitment> for Encoding {
+    fn from(commitment: Commitment) -> Encoding {
+        commitment.to_encoding()
+    }
+}
+
+impl TryFrom<Encoding> for Commitment {
+    type Error = EncodingError;
+
+    fn try_from(bytes: Encoding) -> Result<Self, Self::Error> {
+        Ok(Commitment(bytes.vartime_decompress()?))
+    }
+}
+
+impl TryFrom<[u8; 32]> for Commitment {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Encoding(bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for Commitment {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Encoding::try_from(bytes)?.try_into()
+    }
+}
+
+/// The generators for commitments to a single value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PedersenGens {
+    /// The generator for the value.
+    pub B: Element,
+    /// The generator for the blinding factor.
+    pub B_blinding: Element,
+}
+
+impl Default for PedersenGens {
+    /// Uses [`Element::GENERATOR`] for the value and a derived generator for
+    /// the blinding factor.
+    fn default() -> Self {
+        PedersenGens {
+            B: Element::GENERATOR,
+            B_blinding: Element::derive_generator(DOMAIN, b"blinding"),
+        }
+    }
+}
+
+impl PedersenGens {
+    /// Commits to `value` with the blinding factor `blinding`.
+    ///
+    /// This takes constant time, as both scalars are secret.
+    pub fn commit(&self, value: Fr, blinding: Fr) -> Commitment {
+        let point = Element::multiscalar_mul([value, blinding], [self.B, self.B_blinding])
+            .expect("there is one scalar for each generator");
+        Commitment(point)
+    }
+
+    /// Checks that `commitment` opens to `value` with `blinding`.
+    pub fn open(&self, commitment: &Commitment, value: Fr, blinding: Fr) -> bool {
+        self.commit(value, blinding) == *commitment
+    }
+}
+
+/// The generators for commitments to vectors of up to a fixed length.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorPedersenGens {
+    /// The generators for each entry of the vector.
+    pub G: Vec<Element>,
+    /// The generator for the blinding factor.
+    pub H: Element,
+}
+
+impl VectorPedersenGens {
+    /// Derives the generators for vectors of length `n`.
+    ///
+    /// The generators for a shorter length are a prefix of those for a
+    /// longer one, and `H` is the same as the blinding generator of
+    /// [`PedersenGens::default`].
+    pub fn new(n: usize) -> VectorPedersenGens {
+        let G = Element::derive_generators(VECTOR_DOMAIN, n);
+        VectorPedersenGens {
+            G,
+            H: PedersenGens::default().B_blinding,
+        }
+    }
+
+    /// Commits to `values` with the blinding factor `blinding`, returning an
+    /// error unless there is one value for each generator.
+    ///
+    /// This takes constant time, as the values and blinding factor are
+    /// secret.
+    pub fn commit(&self, values: &[Fr], blinding: Fr) -> Result<Commitment, MultiscalarMulError> {
+        let point = Element::multiscalar_mul(
+            values.iter().chain(core::iter::once(&blinding)),
+            self.G.iter().chain(core::iter::once(&self.H)),
+        )?;
+        Ok(Commitment(point))
+    }
+
+    /// Checks that `commitment` opens to `values` with `blinding`, returning
+    /// an error unless there is one value for each generator.
+    ///
+    /// This is variable-time, as the opening is assumed to be public.
+    pub fn open(
+        &self,
+        commitment: &Commitment,
+        values: &[Fr],
+        blinding: Fr,
+    ) -> Result<bool, MultiscalarMulError> {
+        let point = Element::vartime_multiscalar_mul(
+            values.iter().chain(core::iter::once(&blinding)),
+            self.G.iter().chain(core::iter::once(&self.H)),
+        )?;
+        Ok(point == commitment.0)
+    }
+}
@@ -0,0 +1,45 @@
+//! Public key material from a `frost-dkg` distributed key generation, shared
+//! by the threshold protocols built on it.
+
+use alloc::vec::Vec;
+
+use frost_dkg::{Participant, ParticipantImpl};
+
+use crate::{Element, Fr};
+
+/// Sums the Feldman commitments of every participant whose shares were used
+/// in a completed key generation, giving the commitments to the
+/// coefficients of the joint sharing polynomial, starting with the constant
+/// term, which is the group public key.
+///
+/// Returns `None` if the key generation is incomplete.
+pub(crate) fn group_commitments<I>(participant: &Participant<I, Element>) -> Option<Vec<Element>>
+where
+    I: ParticipantImpl<Element> + Default,
+{
+    if !participant.completed() {
+        return None;
+    }
+
+    let mut commitments = alloc::vec![Element::IDENTITY; participant.get_threshold()];
+    let round1_data = participant.get_received_round1_data();
+    for ordinal in participant.get_received_round2_data().keys() {
+        let sender = round1_data.get(ordinal)?;
+        for (sum, commitment) in commitments.iter_mut().zip(sender.feldman_commitments()) {
+            *sum += commitment.0;
+        }
+    }
+    Some(commitments)
+}
+
+/// Computes the verifying share `s_i * B` of the participant with
+/// `identifier` from the commitments to the sharing polynomial.
+pub(crate) fn verifying_share(commitments: &[Element], identifier: &Fr) -> Element {
+    // Evaluate the committed polynomial at the identifier with Horner's rule.
+    commitments
+        .iter()
+        .rev()
+        .fold(Element::IDENTITY, |acc, commitment| {
+            acc * *identifier + *commitment
+        })
+}
@@ -0,0 +1,73 @@
+//! Variable-time double-base scalar multiplication, as used for verifying
+//! Schnorr-style signatures.
+
+#![allow(non_snake_case)]
+
+use core::cmp::Ordering;
+
+use crate::{
+    Element, Fr,
+    window::{NafLookupTable5, NafLookupTable8},
+};
+
+/// Returns the odd multiples of [`Element::GENERATOR`], building them on
+/// first use.
+#[cfg(feature = "std")]
+fn generator_naf_table() -> &'static NafLookupTable8 {
+    static GENERATOR_NAF_TABLE: once_cell::sync::Lazy<NafLookupTable8> =
+        once_cell::sync::Lazy::new(|| NafLookupTable8::from(&Element::GENERATOR));
+
+    &GENERATOR_NAF_TABLE
+}
+
+impl Element {
+    /// Computes `a * A + b * B` in variable time, where `B` is
+    /// [`Element::GENERATOR`].
+    ///
+    /// The two scalars are recoded in non-adjacent form and processed
+    /// together, so the doublings are shared. `A` is recoded with width 5
+    /// and `B` with width 8, using a table of the odd multiples of `B` that
+    /// is built once and reused when the `std` feature is enabled (and
+    /// rebuilt on every call otherwise).
+    ///
+    /// This is variable-time, and must only be used on public inputs, such
+    /// as when verifying signatures.
+    pub fn vartime_double_scalar_mul_basepoint(a: &Fr, A: &Element, b: &Fr) -> Element {
+        let a_naf = a.non_adjacent_form(5);
+        let b_naf = b.non_adjacent_form(8);
+
+        // Skip the leading zero digits shared by both scalars.
+        let Some(top) = [&a_naf, &b_naf]
+            .iter()
+            .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+            .max()
+        else {
+            return Element::IDENTITY;
+        };
+
+        let table_A = NafLookupTable5::from(A);
+        #[cfg(feature = "std")]
+        let table_B = generator_naf_table();
+        #[cfg(not(feature = "std"))]
+        let table_B = &NafLookupTable8::from(&Element::GENERATOR);
+
+        let mut acc = Element::IDENTITY;
+        for i in (0..=top).rev() {
+            acc = acc.double();
+
+            match a_naf[i].cmp(&0) {
+                Ordering::Greater => acc += table_A.select(a_naf[i] as usize),
+                Ordering::Less => acc -= table_A.select(-a_naf[i] as usize),
+                Ordering::Equal => {}
+            }
+
+            match b_naf[i].cmp(&0) {
+                Ordering::Greater => acc += table_B.select(b_naf[i] as usize),
+                Ordering::Less => acc -= table_B.select(-b_naf[i] as usize),
+                Ordering::Equal => {}
+            }
+        }
+
+        acc
+    }
+}
@@ -1,20 +1,22 @@
-use crate::{Element, Fq, Fr};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::UniformRand;
+//! Implementations of the RustCrypto `group` traits, which only need the
+//! `group` feature and so are available on `no_std` targets without an
+//! allocator or arkworks.
+
+#[cfg(feature = "ecc-group")]
 use blake2::Blake2b512;
-use elliptic_curve::{
-    Group,
-    group::GroupEncoding,
-    hash2curve::{ExpandMsg, ExpandMsgXmd, Expander},
-};
+#[cfg(feature = "ecc-group")]
+use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
+use elliptic_curve::{Group, group::GroupEncoding};
 use rand_core::RngCore;
 use subtle::{Choice, CtOption};
 
+use crate::{Element, Encoding, Fr};
+
 impl Group for Element {
     type Scalar = Fr;
 
     fn random(mut rng: impl RngCore) -> Self {
-        Self::rand(&mut rng)
+        Self::random_from_rng(&mut rng)
     }
 
     fn identity() -> Self {
@@ -38,30 +40,24 @@ impl GroupEncoding for Element {
     type Repr = [u8; 32];
 
     fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
-        match Element::deserialize_compressed(&bytes[..]) {
-            Ok(e) => CtOption::new(e, Choice::from(1)),
-            Err(_) => CtOption::new(Element::IDENTITY, Choice::from(0)),
-        }
+        Encoding(*bytes).decompress_ct()
     }
 
     fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-        match Element::deserialize_compressed_unchecked(&bytes[..]) {
-            Ok(e) => CtOption::new(e, Choice::from(1)),
-            Err(_) => CtOption::new(Element::IDENTITY, Choice::from(0)),
-        }
+        // Every valid encoding is of a point in the prime-order group, so
+        // there are no further checks to skip.
+        Encoding(*bytes).decompress_ct()
     }
 
     fn to_bytes(&self) -> Self::Repr {
-        let mut bytes = [0u8; 32];
-        self.serialize_compressed(&mut bytes[..])
-            .expect("serialization to succeed");
-        bytes
+        self.compress().0
     }
 }
 
+#[cfg(feature = "ecc-group")]
 impl frost_dkg::ScalarHash for Fr {
     fn hash_to_scalar(msg: &[u8]) -> Self {
-        const DST: &'static [u8] = b"DECAF377_XMD:BLAKE2B-512_RO_NUL_";
+        const DST: &[u8] = b"DECAF377_XMD:BLAKE2B-512_RO_NUL_";
 
         let mut expander = ExpandMsgXmd::<Blake2b512>::expand_message(&[msg], &[DST], 64)
             .expect("expander creation to succeed");
@@ -0,0 +1,424 @@
+//! Additively homomorphic ElGamal encryption over decaf377.
+//!
+//! This is "exponential" ElGamal: a message `m` is encrypted to the public
+//! key `P = sk * B` as the [`Ciphertext`] `(r * B, m * B + r * P)` for a
+//! random `r`. Adding ciphertexts adds the messages they encrypt, and
+//! multiplying a ciphertext by a scalar multiplies its message, which makes
+//! the scheme suitable for encrypted tallies.
+//!
+//! Decryption recovers the element `m * B`, so finding `m` itself requires
+//! solving a discrete logarithm. This is only feasible for small messages,
+//! which [`DiscreteLogTable`] finds with the baby-step giant-step algorithm.
+
+use alloc::collections::BTreeMap;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+use crate::{ElGamalError, Element, Encoding, EncodingError, Fr};
+
+#[cfg(feature = "ecc-group")]
+pub mod threshold;
+
+/// A public key for encryption, which has been checked to be the encoding
+/// of an element.
+#[derive(Copy, Clone, Debug)]
+pub struct PublicKey {
+    bytes: Encoding,
+    point: Element,
+}
+
+/// A decryption key and its public key. The decryption key is zeroed when
+/// dropped.
+#[derive(Clone)]
+pub struct ElGamalKeypair {
+    secret: Fr,
+    public: PublicKey,
+}
+
+/// An encryption `(c1, c2) = (r * B, m * B + r * P)` of a message `m` to the
+/// public key `P`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext {
+    /// The ephemeral key `r * B`.
+    pub c1: Element,
+    /// The masked message `m * B + r * P`.
+    pub c2: Element,
+}
+
+impl PublicKey {
+    pub(crate) fn from_point(point: Element) -> Self {
+        PublicKey {
+            bytes: point.vartime_compress(),
+            point,
+        }
+    }
+
+    /// Returns the encoding of this public key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.bytes.0
+    }
+
+    /// Returns the element `sk * B` of this public key.
+    pub fn to_element(&self) -> Element {
+        self.point
+    }
+
+    /// Encrypts `message` with fresh randomness.
+    pub fn encrypt<R: CryptoRngCore>(&self, message: Fr, rng: &mut R) -> Ciphertext {
+        let mut r = Fr::rand(rng);
+        let ciphertext = self.encrypt_with_randomness(message, &r);
+        r.zeroize();
+        ciphertext
+    }
+
+    /// Encrypts `message` with the randomness `r`.
+    ///
+    /// The randomness must be uniformly random and never reused, or the
+    /// message can be recovered; this is mainly useful for test vectors and
+    /// for proving statements about the ciphertext.
+    pub fn encrypt_with_randomness(&self, message: Fr, r: &Fr) -> Ciphertext {
+        let shared = self.point.scalar_mul_ct(&r);
+        Ciphertext {
+            c1: Element::basepoint_mul_ct(r),
+            c2: Element::basepoint_mul_ct(&message) + shared,
+        }
+    }
+
+    /// Re-randomizes `ciphertext`, returning a fresh encryption of the same
+    /// message that cannot be linked to the original.
+    pub fn rerandomize<R: CryptoRngCore>(
+        &self,
+        ciphertext: &Ciphertext,
+        rng: &mut R,
+    ) -> Ciphertext {
+        *ciphertext + self.encrypt(Fr::ZERO, rng)
+    }
+}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for PublicKey {}
+
+impl TryFrom<Encoding> for PublicKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: Encoding) -> Result<Self, Self::Error> {
+        Ok(PublicKey {
+            point: bytes.vartime_decompress()?,
+            bytes,
+        })
+    }
+}
+
+impl TryFrom<[u8; 32]> for PublicKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Encoding(bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Encoding::try_from(bytes)?.try_into()
+    }
+}
+
+impl From<PublicKey> for [u8; 32] {
+    fn from(public: PublicKey) -> [u8; 32] {
+        public.to_bytes()
+    }
+}
+
+impl ElGamalKeypair {
+    /// Generates a new keypair.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Fr::rand(rng).into()
+    }
+
+    /// Returns the encoding of the decryption key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    /// Returns the public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public
+    }
+
+    /// Decrypts `ciphertext` to the element `m * B`.
+    pub fn decrypt_to_element(&self, ciphertext: &Ciphertext) -> Element {
+        let shared = ciphertext.c1.scalar_mul_ct(&self.secret);
+        ciphertext.c2 - shared
+    }
+
+    /// Decrypts `ciphertext` to a message in the range of `table`.
+    ///
+    /// Returns an error if the message is out of range. This is
+    /// variable-time in the message.
+    pub fn decrypt(
+        &self,
+        ciphertext: &Ciphertext,
+        table: &DiscreteLogTable,
+    ) -> Result<u64, ElGamalError> {
+        table
+            .vartime_solve(&self.decrypt_to_element(ciphertext))
+            .ok_or(ElGamalError::MessageOutOfRange)
+    }
+}
+
+impl From<Fr> for ElGamalKeypair {
+    fn from(secret: Fr) -> ElGamalKeypair {
+        ElGamalKeypair {
+            public: PublicKey::from_point(Element::basepoint_mul_ct(&secret)),
+            secret,
+        }
+    }
+}
+
+impl TryFrom<[u8; 32]> for ElGamalKeypair {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(Fr::from_bytes_checked(&bytes)?.into())
+    }
+}
+
+impl core::fmt::Debug for ElGamalKeypair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ElGamalKeypair")
+            .field("public", &self.public)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ElGamalKeypair {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Ciphertext {
+    /// The encryption of zero with zero randomness, which is the identity
+    /// for addition.
+    pub const ZERO: Ciphertext = Ciphertext {
+        c1: Element::IDENTITY,
+        c2: Element::IDENTITY,
+    };
+
+    /// Returns the encoding of `c1` followed by that of `c2`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.c1.vartime_compress().0);
+        bytes[32..].copy_from_slice(&self.c2.vartime_compress().0);
+        bytes
+    }
+}
+
+impl TryFrom<[u8; 64]> for Ciphertext {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 64]) -> Result<Self, Self::Error> {
+        Ok(Ciphertext {
+            c1: Encoding::try_from(&bytes[..32])?.vartime_decompress()?,
+            c2: Encoding::try_from(&bytes[32..])?.vartime_decompress()?,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for Ciphertext {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 64] = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        bytes.try_into()
+    }
+}
+
+impl Add for Ciphertext {
+    type Output = Ciphertext;
+
+    fn add(self, rhs: Ciphertext) -> Ciphertext {
+        Ciphertext {
+            c1: self.c1 + rhs.c1,
+            c2: self.c2 + rhs.c2,
+        }
+    }
+}
+
+impl AddAssign for Ciphertext {
+    fn add_assign(&mut self, rhs: Ciphertext) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Ciphertext {
+    type Output = Ciphertext;
+
+    fn sub(self, rhs: Ciphertext) -> Ciphertext {
+        Ciphertext {
+            c1: self.c1 - rhs.c1,
+            c2: self.c2 - rhs.c2,
+        }
+    }
+}
+
+impl SubAssign for Ciphertext {
+    fn sub_assign(&mut self, rhs: Ciphertext) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Ciphertext {
+    type Output = Ciphertext;
+
+    fn neg(self) -> Ciphertext {
+        Ciphertext {
+            c1: -self.c1,
+            c2: -self.c2,
+        }
+    }
+}
+
+impl Mul<Fr> for Ciphertext {
+    type Output = Ciphertext;
+
+    fn mul(self, rhs: Fr) -> Ciphertext {
+        Ciphertext {
+            c1: self.c1 * rhs,
+            c2: self.c2 * rhs,
+        }
+    }
+}
+
+impl Mul<Ciphertext> for Fr {
+    type Output = Ciphertext;
+
+    fn mul(self, rhs: Ciphertext) -> Ciphertext {
+        rhs * self
+    }
+}
+
+impl MulAssign<Fr> for Ciphertext {
+    fn mul_assign(&mut self, rhs: Fr) {
+        *self = *self * rhs;
+    }
+}
+
+impl core::iter::Sum for Ciphertext {
+    fn sum<I: Iterator<Item = Ciphertext>>(iter: I) -> Ciphertext {
+        iter.fold(Ciphertext::ZERO, Add::add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Ciphertext> for Ciphertext {
+    fn sum<I: Iterator<Item = &'a Ciphertext>>(iter: I) -> Ciphertext {
+        iter.fold(Ciphertext::ZERO, |acc, ciphertext| acc + *ciphertext)
+    }
+}
+
+/// A precomputed table for finding discrete logs `m` of elements `m * B`
+/// with `0 <= m <= max`, using the baby-step giant-step algorithm.
+///
+/// A table with `n` entries stores `j * B` for `0 <= j < n`, and a search
+/// takes up to `max / n + 1` giant steps of subtracting `n * B`, so the
+/// table size trades memory for decryption time. The default of
+/// [`DiscreteLogTable::new`] balances the two.
+#[derive(Clone, Debug)]
+pub struct DiscreteLogTable {
+    baby_steps: BTreeMap<[u8; 32], u64>,
+    giant_step: Element,
+    table_size: u64,
+    max: u64,
+}
+
+impl DiscreteLogTable {
+    /// The largest number of entries [`DiscreteLogTable::new`] precomputes,
+    /// which takes a few megabytes.
+    pub const MAX_DEFAULT_TABLE_SIZE: u64 = 1 << 16;
+
+    /// Precomputes a table for messages up to `max`, with about
+    /// `sqrt(max)` entries, but no more than
+    /// [`DiscreteLogTable::MAX_DEFAULT_TABLE_SIZE`].
+    ///
+    /// Up to `max = 2^32`, a search takes at most as many giant steps as
+    /// there are entries. Beyond that, the table stays the same size and the
+    /// search time grows linearly with `max`, so decrypting messages much
+    /// larger than `2^32` is impractical; use
+    /// [`DiscreteLogTable::with_table_size`] to trade more memory for time.
+    pub fn new(max: u64) -> DiscreteLogTable {
+        // With more than sqrt(max) entries, a search takes at most as many
+        // giant steps as there are entries.
+        let table_size = (max.isqrt() + 1).min(Self::MAX_DEFAULT_TABLE_SIZE);
+        DiscreteLogTable::with_table_size(max, table_size)
+    }
+
+    /// Precomputes a table for messages up to `max`, with `table_size`
+    /// entries.
+    ///
+    /// The table is held in memory, at several dozen bytes per entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_size` is zero.
+    pub fn with_table_size(max: u64, table_size: u64) -> DiscreteLogTable {
+        assert!(table_size > 0, "the table must have at least one entry");
+
+        let mut points = alloc::vec::Vec::with_capacity(table_size as usize);
+        let mut point = Element::IDENTITY;
+        for _ in 0..table_size {
+            points.push(point);
+            point += Element::GENERATOR;
+        }
+        let baby_steps = Element::batch_compress(&points)
+            .into_iter()
+            .zip(0..)
+            .map(|(encoding, j)| (encoding.0, j))
+            .collect();
+
+        DiscreteLogTable {
+            baby_steps,
+            giant_step: point,
+            table_size,
+            max,
+        }
+    }
+
+    /// Returns the largest message this table can find.
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// Returns the number of entries in this table.
+    pub fn table_size(&self) -> u64 {
+        self.table_size
+    }
+
+    /// Finds `m` with `0 <= m <= max` such that `point` is `m * B`, or
+    /// returns `None` if there is none.
+    ///
+    /// This is variable-time in `m`.
+    pub fn vartime_solve(&self, point: &Element) -> Option<u64> {
+        let mut target = *point;
+        for i in 0..=self.max / self.table_size {
+            if let Some(j) = self.baby_steps.get(&target.vartime_compress().0) {
+                // Since i * table_size <= max, only adding j can overflow,
+                // and then m is out of range anyway.
+                let m = (i * self.table_size).checked_add(*j)?;
+                return (m <= self.max).then_some(m);
+            }
+            target -= self.giant_step;
+        }
+        None
+    }
+}
@@ -0,0 +1,378 @@
+//! Threshold decryption of ElGamal ciphertexts, with key shares from a
+//! `frost-dkg` distributed key generation.
+//!
+//! After the key generation, each participant holds a [`KeyShare`] `sk_i`
+//! of a decryption key `sk` that nobody knows, and everyone can compute the
+//! [`ThresholdPublicKey`], which determines both the public key `sk * B`
+//! and each participant's verifying share `sk_i * B`.
+//!
+//! To decrypt a ciphertext `(c1, c2)`, each participant publishes a
+//! [`DecryptionShare`] `D_i = sk_i * c1`, along with a Chaum-Pedersen proof
+//! that `D_i` and the verifying share have the same discrete log. Once `t`
+//! shares are verified, [`combine`] interpolates them to `sk * c1` with
+//! Lagrange coefficients, and removes it from `c2`. The key is never
+//! reconstructed.
+//!
+//! The key generation must use [`Element::GENERATOR`] as its message
+//! generator, which is the default of `frost_dkg::Parameters::new`.
+//!
+//! # Proofs
+//!
+//! The proof for `D_i` is a pair `(e, s)` with `s = k + e * sk_i` for a
+//! random nonce `k`, where `e` is the Blake2b-512 hash of
+//!
+//! ```text
+//! "decaf377-elgamal-decryption-share" || i || Y_i || c1 || D_i || k * B || k * c1
+//! ```
+//!
+//! read in little-endian order and reduced modulo the group order. Elements
+//! are serialized with [`Element::vartime_compress`], and the identifier `i`
+//! with [`Fr::to_bytes`].
+
+use alloc::vec::Vec;
+
+use blake2::{Blake2b512, Digest};
+use frost_dkg::{Participant, ParticipantImpl, SecretShare};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+use crate::dkg;
+use crate::elgamal::{Ciphertext, DiscreteLogTable, PublicKey};
+use crate::interpolation::lagrange_coefficient;
+use crate::{ElGamalError, Element, EncodingError, Fr};
+
+/// Domain separator for decryption share proofs.
+const PROOF_DOMAIN: &[u8] = b"decaf377-elgamal-decryption-share";
+
+/// A participant's share of the decryption key, which is zeroed when
+/// dropped.
+#[derive(Clone)]
+pub struct KeyShare {
+    identifier: Fr,
+    secret: Fr,
+}
+
+/// The public key material from a key generation: the commitments to the
+/// coefficients of the sharing polynomial, whose constant term is the
+/// public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdPublicKey {
+    public_key: PublicKey,
+    commitments: Vec<Element>,
+}
+
+/// A proof that a decryption share was computed with the key share behind
+/// the participant's verifying share.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChaumPedersenProof {
+    e: Fr,
+    s: Fr,
+}
+
+/// A participant's share `D_i = sk_i * c1` of the decryption of a
+/// ciphertext, with a proof of correctness.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecryptionShare {
+    identifier: Fr,
+    share: Element,
+    proof: ChaumPedersenProof,
+}
+
+/// Computes the proof challenge for the decryption share `D_i` of
+/// participant `i`, with nonce commitments `A_1 = k * B` and `A_2 = k * c1`.
+#[allow(non_snake_case)]
+fn challenge(
+    identifier: &Fr,
+    verifying_share: &Element,
+    c1: &Element,
+    D_i: &Element,
+    A_1: &Element,
+    A_2: &Element,
+) -> Fr {
+    let mut hasher = Blake2b512::new();
+    hasher.update(PROOF_DOMAIN);
+    hasher.update(identifier.to_bytes());
+    for point in [verifying_share, c1, D_i, A_1, A_2] {
+        hasher.update(point.vartime_compress().0);
+    }
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+impl KeyShare {
+    /// Creates a key share from a participant's identifier and share of the
+    /// decryption key; the identifier must be nonzero.
+    pub fn new(identifier: Fr, secret: Fr) -> Result<KeyShare, ElGamalError> {
+        if identifier == Fr::ZERO {
+            return Err(ElGamalError::InvalidZeroIdentifier);
+        }
+        Ok(KeyShare { identifier, secret })
+    }
+
+    /// Returns the identifier of this participant.
+    pub fn identifier(&self) -> Fr {
+        self.identifier
+    }
+
+    /// Returns the verifying share `sk_i * B` for this share.
+    pub fn verifying_share(&self) -> Element {
+        Element::basepoint_mul_ct(&self.secret)
+    }
+
+    /// Computes this participant's share of the decryption of `ciphertext`,
+    /// with a proof of correctness.
+    #[allow(non_snake_case)]
+    pub fn decryption_share<R: CryptoRngCore>(
+        &self,
+        ciphertext: &Ciphertext,
+        rng: &mut R,
+    ) -> DecryptionShare {
+        let D_i = ciphertext.c1.scalar_mul_ct(&self.secret);
+
+        let mut k = Fr::rand(rng);
+        let A_2 = ciphertext.c1.scalar_mul_ct(&k);
+        let e = challenge(
+            &self.identifier,
+            &self.verifying_share(),
+            &ciphertext.c1,
+            &D_i,
+            &Element::basepoint_mul_ct(&k),
+            &A_2,
+        );
+        let s = k + e * self.secret;
+        k.zeroize();
+
+        DecryptionShare {
+            identifier: self.identifier,
+            share: D_i,
+            proof: ChaumPedersenProof { e, s },
+        }
+    }
+}
+
+impl TryFrom<&SecretShare<Fr>> for KeyShare {
+    type Error = ElGamalError;
+
+    fn try_from(share: &SecretShare<Fr>) -> Result<Self, Self::Error> {
+        KeyShare::new(share.identifier.0, share.value.0)
+    }
+}
+
+impl core::fmt::Debug for KeyShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyShare")
+            .field("identifier", &self.identifier)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for KeyShare {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl ThresholdPublicKey {
+    /// Creates the public key material from the commitments to the
+    /// coefficients of the sharing polynomial, starting with the constant
+    /// term; there is one commitment for each of the `t` shares needed to
+    /// decrypt.
+    pub fn new(commitments: Vec<Element>) -> Result<ThresholdPublicKey, ElGamalError> {
+        let public_key = commitments
+            .first()
+            .ok_or(ElGamalError::IncompleteKeyGeneration)?;
+        Ok(ThresholdPublicKey {
+            public_key: PublicKey::from_point(*public_key),
+            commitments,
+        })
+    }
+
+    /// Collects the public key material from a participant that has
+    /// completed the key generation, by summing the Feldman commitments of
+    /// every participant whose shares were used.
+    pub fn from_participant<I>(
+        participant: &Participant<I, Element>,
+    ) -> Result<ThresholdPublicKey, ElGamalError>
+    where
+        I: ParticipantImpl<Element> + Default,
+    {
+        let commitments =
+            dkg::group_commitments(participant).ok_or(ElGamalError::IncompleteKeyGeneration)?;
+        ThresholdPublicKey::new(commitments)
+    }
+
+    /// Returns the public key to encrypt to.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the number of shares needed to decrypt.
+    pub fn threshold(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Returns the commitments to the coefficients of the sharing
+    /// polynomial.
+    pub fn commitments(&self) -> &[Element] {
+        &self.commitments
+    }
+
+    /// Computes the verifying share `sk_i * B` of the participant with
+    /// `identifier`.
+    pub fn verifying_share(&self, identifier: &Fr) -> Element {
+        dkg::verifying_share(&self.commitments, identifier)
+    }
+}
+
+impl ChaumPedersenProof {
+    /// Returns the encoding of the challenge `e` followed by that of the
+    /// response `s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.e.to_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<[u8; 64]> for ChaumPedersenProof {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 64]) -> Result<Self, Self::Error> {
+        Ok(ChaumPedersenProof {
+            e: Fr::from_bytes_checked(&bytes[..32].try_into().expect("32 bytes"))?,
+            s: Fr::from_bytes_checked(&bytes[32..].try_into().expect("32 bytes"))?,
+        })
+    }
+}
+
+impl DecryptionShare {
+    /// Returns the identifier of the participant who computed this share.
+    pub fn identifier(&self) -> Fr {
+        self.identifier
+    }
+
+    /// Returns the share `D_i = sk_i * c1`.
+    pub fn share(&self) -> Element {
+        self.share
+    }
+
+    /// Returns the proof of correctness of this share.
+    pub fn proof(&self) -> &ChaumPedersenProof {
+        &self.proof
+    }
+
+    /// Checks that this share is the decryption share of `ciphertext` for
+    /// the participant whose verifying share is `verifying_share`.
+    #[allow(non_snake_case)]
+    pub fn verify(
+        &self,
+        ciphertext: &Ciphertext,
+        verifying_share: &Element,
+    ) -> Result<(), ElGamalError> {
+        let ChaumPedersenProof { e, s } = self.proof;
+        // A_1 = s * B - e * Y_i and A_2 = s * c1 - e * D_i.
+        let A_1 = Element::vartime_double_scalar_mul_basepoint(&-e, verifying_share, &s);
+        let A_2 = ciphertext.c1 * s - self.share * e;
+        let expected = challenge(
+            &self.identifier,
+            verifying_share,
+            &ciphertext.c1,
+            &self.share,
+            &A_1,
+            &A_2,
+        );
+        if expected == e {
+            Ok(())
+        } else {
+            Err(ElGamalError::InvalidDecryptionShare {
+                culprit: self.identifier,
+            })
+        }
+    }
+
+    /// Returns the encoding of the identifier, the share and the proof, in
+    /// that order.
+    pub fn to_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        bytes[..32].copy_from_slice(&self.identifier.to_bytes());
+        bytes[32..64].copy_from_slice(&self.share.vartime_compress().0);
+        bytes[64..].copy_from_slice(&self.proof.to_bytes());
+        bytes
+    }
+}
+
+impl TryFrom<[u8; 128]> for DecryptionShare {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 128]) -> Result<Self, Self::Error> {
+        Ok(DecryptionShare {
+            identifier: Fr::from_bytes_checked(&bytes[..32].try_into().expect("32 bytes"))?,
+            share: crate::Encoding::try_from(&bytes[32..64])?.vartime_decompress()?,
+            proof: <[u8; 64]>::try_from(&bytes[64..])
+                .expect("64 bytes")
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for DecryptionShare {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 128] = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        bytes.try_into()
+    }
+}
+
+/// Verifies the decryption shares of `ciphertext` and combines them into
+/// the decrypted element `m * B`.
+///
+/// There must be at least as many shares as the threshold of `public`,
+/// each from a different participant. The first share that fails to verify
+/// is reported as the culprit.
+pub fn combine(
+    ciphertext: &Ciphertext,
+    shares: &[DecryptionShare],
+    public: &ThresholdPublicKey,
+) -> Result<Element, ElGamalError> {
+    if shares.len() < public.threshold() {
+        return Err(ElGamalError::TooFewDecryptionShares);
+    }
+
+    let identifiers: Vec<Fr> = shares.iter().map(|share| share.identifier).collect();
+    for (i, identifier) in identifiers.iter().enumerate() {
+        if *identifier == Fr::ZERO {
+            return Err(ElGamalError::InvalidZeroIdentifier);
+        }
+        if identifiers[..i].contains(identifier) {
+            return Err(ElGamalError::DuplicateDecryptionShare);
+        }
+    }
+
+    for share in shares {
+        share.verify(ciphertext, &public.verifying_share(&share.identifier))?;
+    }
+
+    let lambdas: Vec<Fr> = identifiers
+        .iter()
+        .map(|identifier| lagrange_coefficient(identifier, &identifiers))
+        .collect();
+    let mask = Element::vartime_multiscalar_mul(&lambdas, shares.iter().map(|share| share.share))
+        .expect("there is one coefficient for each share");
+    Ok(ciphertext.c2 - mask)
+}
+
+/// Verifies and combines the decryption shares of `ciphertext` with
+/// [`combine`], and then finds the message in the range of `table`.
+pub fn decrypt(
+    ciphertext: &Ciphertext,
+    shares: &[DecryptionShare],
+    public: &ThresholdPublicKey,
+    table: &DiscreteLogTable,
+) -> Result<u64, ElGamalError> {
+    table
+        .vartime_solve(&combine(ciphertext, shares, public)?)
+        .ok_or(ElGamalError::MessageOutOfRange)
+}
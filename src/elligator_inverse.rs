@@ -0,0 +1,253 @@
+//! Inverting the Elligator map, and representing elements by uniformly
+//! random byte strings.
+//!
+//! These only use the public API of [`Element`] together with field
+//! arithmetic, so the same code serves both the arkworks and the minimal
+//! curve backends.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use rand_core::CryptoRngCore;
+
+#[cfg(feature = "alloc")]
+use crate::{COEFF_D, ZETA, sign::Sign};
+use crate::{Element, Fq};
+
+/// An upper bound on the number of preimages of an element under
+/// [`Element::encode_to_curve`].
+///
+/// Each element has four representatives on the Jacobi quartic, each of
+/// which is hit by at most one `r = ZETA * r_0^2`, and each `r` has two
+/// square roots `r_0`.
+#[cfg(feature = "alloc")]
+const MAX_PREIMAGES: usize = 8;
+
+impl Element {
+    /// Returns every field element `r_0` such that
+    /// `Element::encode_to_curve(&r_0) == *self`.
+    ///
+    /// A typical element has about four preimages, and some have none. The
+    /// preimages are returned in no particular order.
+    ///
+    /// This is variable-time, and should only be used on public points.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_encode_to_curve_preimages(&self) -> Vec<Fq> {
+        let a = -Fq::ONE;
+        let d = COEFF_D;
+        let a_minus_2d = a - d - d;
+
+        // The Elligator map first computes r = ZETA * r_0^2, then a point
+        // (s, t) on the Jacobi quartic t^2 = a^2 s^4 + 2 (a - 2d) s^2 + 1,
+        // which is mapped to the curve. Here we work backwards, collecting
+        // the possible values of r.
+        let mut candidates = Vec::with_capacity(4);
+
+        let s = self.vartime_compress_to_field();
+        if s == Fq::ZERO {
+            // The map gives s = 0 exactly when r = 0, r = -1, or the
+            // denominator (d r - (d - a)) ((d - a) r - d) vanishes.
+            candidates.push(Fq::ZERO);
+            candidates.push(-Fq::ONE);
+            if let (Some(d_inv), Some(d_minus_a_inv)) = (d.inverse(), (d - a).inverse()) {
+                candidates.push((d - a) * d_inv);
+                candidates.push(d * d_minus_a_inv);
+            }
+        } else {
+            // Recover t for the representative with this s, as in steps 3-7
+            // of decoding: the decoded point has y = (1 - a s^2) / t.
+            let ss = s.square();
+            let u_1 = Fq::ONE + a * ss;
+            let u_2 = u_1.square() - (d + d + d + d) * ss;
+            let (_, mut v) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &(u_2 * u_1.square()));
+            if ((s + s) * u_1 * v).is_negative() {
+                v = -v;
+            }
+            let t = (v * u_1)
+                .inverse()
+                .expect("valid encodings have u_1 and v nonzero");
+
+            // The other representatives are (-s, -t), and (1/(a s), t/s^2)
+            // with its negation.
+            let s_inv = s.inverse().expect("s is nonzero");
+            let t_div_ss = t * s_inv.square();
+            let representatives = [(s, t), (-s, -t), (-s_inv, t_div_ss), (s_inv, -t_div_ss)];
+
+            for (s, t) in representatives {
+                // The map outputs a nonnegative s when r / ZETA is square,
+                // with s^2 (a - 2d) / (t + 1) = -(r + 1) / (r - 1), and a
+                // negative s otherwise, with the reciprocal r.
+                let c = a_minus_2d * s.square();
+                let (num, den) = if s.is_nonnegative() {
+                    (c - t - Fq::ONE, c + t + Fq::ONE)
+                } else {
+                    (c + t + Fq::ONE, c - t - Fq::ONE)
+                };
+                if let Some(den_inv) = den.inverse() {
+                    candidates.push(num * den_inv);
+                }
+            }
+        }
+
+        // Each candidate r gives preimages ±sqrt(r / ZETA), which are then
+        // checked against the forward map.
+        let mut preimages = Vec::with_capacity(MAX_PREIMAGES);
+        for r in candidates {
+            let (was_square, r_0) = Fq::sqrt_ratio_zeta_ct(&r, &ZETA);
+            if !bool::from(was_square) {
+                continue;
+            }
+            for r_0 in [r_0, -r_0] {
+                if !preimages.contains(&r_0) && Element::encode_to_curve(&r_0) == *self {
+                    preimages.push(r_0);
+                }
+            }
+        }
+
+        debug_assert!(preimages.len() <= MAX_PREIMAGES);
+        preimages
+    }
+
+    /// Samples a 64-byte string that is indistinguishable from uniformly
+    /// random bytes, and which [`Element::from_uniform_representative`] maps
+    /// back to this element.
+    ///
+    /// This uses Tibouchi's Elligator Squared construction: the output
+    /// encodes a uniformly random pair `(u_1, u_2)` with
+    /// `Element::hash_to_curve(&u_1, &u_2) == *self`, with each field element
+    /// lifted to a uniformly random 256-bit integer in its residue class.
+    ///
+    /// This is variable-time, so the running time may reveal information
+    /// about the element (though not about the representative).
+    #[cfg(feature = "alloc")]
+    pub fn to_uniform_representative<R: CryptoRngCore>(&self, rng: &mut R) -> [u8; 64] {
+        loop {
+            // Pick u_1 at random, then choose uniformly among the preimages
+            // u_2 of the difference, retrying with probability depending on
+            // how many there are, so that the pair is uniform.
+            let u_1 = Fq::rand(rng);
+            let preimages =
+                (*self - Element::encode_to_curve(&u_1)).vartime_encode_to_curve_preimages();
+            let j = (rng.next_u32() as usize) % MAX_PREIMAGES;
+            let Some(u_2) = preimages.get(j) else {
+                continue;
+            };
+
+            // Start over if either lift fails, rather than retrying the lift
+            // alone, since that would bias the lifted values.
+            if let (Some(bytes_1), Some(bytes_2)) = (lift(&u_1, rng), lift(u_2, rng)) {
+                let mut bytes = [0u8; 64];
+                bytes[..32].copy_from_slice(&bytes_1);
+                bytes[32..].copy_from_slice(&bytes_2);
+                return bytes;
+            }
+        }
+    }
+
+    /// Maps a representative produced by
+    /// [`Element::to_uniform_representative`] back to its element.
+    ///
+    /// Every 64-byte string is the representative of some element.
+    pub fn from_uniform_representative(bytes: &[u8; 64]) -> Element {
+        let u_1 = Fq::from_le_bytes_mod_order(&bytes[..32]);
+        let u_2 = Fq::from_le_bytes_mod_order(&bytes[32..]);
+        Element::hash_to_curve(&u_1, &u_2)
+    }
+}
+
+/// Returns a uniformly random 256-bit integer congruent to `u` modulo `q`,
+/// encoded in little-endian order, or `None` with probability about `1/7`.
+#[cfg(feature = "alloc")]
+fn lift<R: CryptoRngCore>(u: &Fq, rng: &mut R) -> Option<[u8; 32]> {
+    // Since 2^256 / q is between 13 and 14, every residue has 13 or 14 lifts
+    // u + k q below 2^256. Picking k from a fixed range and rejecting the
+    // values that overflow makes every 256-bit integer equally likely.
+    let k = rng.next_u32() % 16;
+
+    let mut limbs = u.to_le_limbs();
+    for _ in 0..k {
+        let mut carry = false;
+        for (limb, q_limb) in limbs.iter_mut().zip(Fq::MODULUS_LIMBS) {
+            let (sum, carry_1) = limb.overflowing_add(q_limb);
+            let (sum, carry_2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = carry_1 | carry_2;
+        }
+        if carry {
+            return None;
+        }
+    }
+
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    Some(bytes)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+    use rand_core::OsRng;
+
+    fn fq_strategy() -> BoxedStrategy<Fq> {
+        any::<[u8; 32]>()
+            .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+            .boxed()
+    }
+
+    #[test]
+    fn identity_preimages_map_to_identity() {
+        let preimages = Element::IDENTITY.vartime_encode_to_curve_preimages();
+        assert!(preimages.len() <= MAX_PREIMAGES);
+        for r_0 in preimages {
+            assert_eq!(Element::encode_to_curve(&r_0), Element::IDENTITY);
+        }
+    }
+
+    #[test]
+    fn uniform_representatives_use_high_bits() {
+        // Field elements never set the top three bits, but lifted
+        // representatives should.
+        let point = Element::GENERATOR;
+        let mut high_bits = 0u8;
+        for _ in 0..32 {
+            let bytes = point.to_uniform_representative(&mut OsRng);
+            high_bits |= (bytes[31] | bytes[63]) >> 5;
+        }
+        assert_eq!(high_bits, 0b111);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn encode_to_curve_preimages_are_complete(r_0 in fq_strategy()) {
+            let point = Element::encode_to_curve(&r_0);
+            let preimages = point.vartime_encode_to_curve_preimages();
+            prop_assert!(preimages.contains(&r_0));
+            prop_assert!(preimages.contains(&-r_0));
+            prop_assert!(preimages.len() <= MAX_PREIMAGES);
+            for preimage in preimages {
+                prop_assert_eq!(Element::encode_to_curve(&preimage), point);
+            }
+        }
+
+        #[test]
+        fn uniform_representative_round_trip(r_1 in fq_strategy(), r_2 in fq_strategy()) {
+            let point = Element::hash_to_curve(&r_1, &r_2);
+            let bytes = point.to_uniform_representative(&mut OsRng);
+            prop_assert_eq!(Element::from_uniform_representative(&bytes), point);
+        }
+
+        #[test]
+        fn lifts_are_congruent(u in fq_strategy()) {
+            if let Some(lifted) = lift(&u, &mut OsRng) {
+                prop_assert_eq!(Fq::from_le_bytes_mod_order(&lifted), u);
+            }
+        }
+    }
+}
@@ -1,16 +1,205 @@
+/// Errors that can occur when decoding field elements and decaf377 points.
+///
+/// More variants may be added as decoding reports failures in more detail.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum EncodingError {
+    /// The encoding was invalid.
+    ///
+    /// This is no longer returned, since each way an encoding can be invalid
+    /// now has its own variant.
+    #[deprecated(
+        note = "decoding reports `NonZeroHighBits`, `NonCanonicalFieldElement`, `NegativeS` or `NotASquare` instead"
+    )]
     InvalidEncoding,
+    /// The top three bits of the last byte of a point encoding were set.
+    NonZeroHighBits,
+    /// The bytes encoded an integer greater than or equal to the field
+    /// modulus.
+    NonCanonicalFieldElement,
+    /// The `s` value of a point encoding was negative.
+    NegativeS,
+    /// The square root in the decoding formula did not exist, so the
+    /// encoding is not that of any point.
+    NotASquare,
+    /// The input did not have the length of an encoding.
     InvalidSliceLength,
 }
 
 impl core::fmt::Display for EncodingError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let msg = match self {
+            #[allow(deprecated)]
             Self::InvalidEncoding => "Invalid Decaf377 encoding",
+            Self::NonZeroHighBits => "Invalid Decaf377 encoding: high bits are set",
+            Self::NonCanonicalFieldElement => "Non-canonical field element encoding",
+            Self::NegativeS => "Invalid Decaf377 encoding: s is negative",
+            Self::NotASquare => "Invalid Decaf377 encoding: no square root exists",
             Self::InvalidSliceLength => "Invalid length bytes in encoded point",
         };
 
         msg.fmt(f)
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodingError {}
+
+/// Keeps the [`EncodingError`] as the source of an
+/// [`IoError`](ark_serialize::SerializationError::IoError), since
+/// `SerializationError` has no variant to carry it directly.
+#[cfg(feature = "arkworks")]
+impl From<EncodingError> for ark_serialize::SerializationError {
+    fn from(err: EncodingError) -> Self {
+        ark_serialize::SerializationError::IoError(ark_std::io::Error::new(
+            ark_std::io::ErrorKind::InvalidData,
+            err,
+        ))
+    }
+}
+
+/// Errors that can occur when computing a multiscalar multiplication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiscalarMulError {
+    /// The scalars and points had different lengths.
+    LengthMismatch { scalars: usize, points: usize },
+}
+
+impl core::fmt::Display for MultiscalarMulError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LengthMismatch { scalars, points } => write!(
+                f,
+                "Mismatched multiscalar multiplication inputs: {scalars} scalars but {points} points"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MultiscalarMulError {}
+
+/// Errors that can occur in Diffie-Hellman key agreement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAgreementError {
+    /// The shared secret was the identity, because the public key was the
+    /// identity or the secret was zero.
+    IdentitySharedSecret,
+}
+
+impl core::fmt::Display for KeyAgreementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::IdentitySharedSecret => "Key agreement produced the identity",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyAgreementError {}
+
+/// Errors that can occur in ElGamal decryption, including threshold
+/// decryption.
+#[cfg(feature = "elgamal")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElGamalError {
+    /// The decrypted message was larger than the discrete log table allows.
+    MessageOutOfRange,
+    /// A participant identifier was zero.
+    InvalidZeroIdentifier,
+    /// The key generation had not completed, or produced no commitments.
+    IncompleteKeyGeneration,
+    /// There were fewer decryption shares than the threshold.
+    TooFewDecryptionShares,
+    /// Two decryption shares were from the same participant.
+    DuplicateDecryptionShare,
+    /// A participant's decryption share failed to verify.
+    InvalidDecryptionShare { culprit: crate::Fr },
+}
+
+#[cfg(feature = "elgamal")]
+impl core::fmt::Display for ElGamalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MessageOutOfRange => {
+                "Decrypted message is out of range of the discrete log table".fmt(f)
+            }
+            Self::InvalidZeroIdentifier => "Identifier must be nonzero".fmt(f),
+            Self::IncompleteKeyGeneration => "Key generation has not completed".fmt(f),
+            Self::TooFewDecryptionShares => "Too few decryption shares".fmt(f),
+            Self::DuplicateDecryptionShare => {
+                "Multiple decryption shares from the same participant".fmt(f)
+            }
+            Self::InvalidDecryptionShare { culprit } => {
+                write!(f, "Invalid decryption share from participant {culprit:?}")
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "elgamal", feature = "std"))]
+impl std::error::Error for ElGamalError {}
+
+/// Errors that can occur in FROST key generation and signing.
+#[cfg(feature = "frost")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrostError {
+    /// Fewer than two signers were required.
+    InvalidMinSigners,
+    /// There were fewer participants than required signers.
+    InvalidMaxSigners,
+    /// The number of polynomial coefficients did not match the number of
+    /// required signers.
+    InvalidCoefficients,
+    /// An identifier was zero.
+    InvalidZeroIdentifier,
+    /// The distributed key generation had not completed.
+    IncompleteKeyGeneration,
+    /// The signing package had fewer commitments than required signers.
+    IncorrectNumberOfCommitments,
+    /// The signing package had no commitments from the signer.
+    MissingCommitment,
+    /// The signer's commitments in the signing package did not match their
+    /// nonces.
+    IncorrectCommitment,
+    /// A commitment was the identity.
+    IdentityCommitment,
+    /// A signature share or verifying share was from or for a participant
+    /// outside the signing package.
+    UnknownIdentifier,
+    /// A participant's signature share was invalid.
+    InvalidSignatureShare { culprit: crate::frost::Identifier },
+    /// The aggregated signature was invalid, although every share was valid.
+    InvalidSignature,
+}
+
+#[cfg(feature = "frost")]
+impl core::fmt::Display for FrostError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidMinSigners => "Minimum number of signers must be at least 2".fmt(f),
+            Self::InvalidMaxSigners => {
+                "Number of participants must be at least the minimum number of signers".fmt(f)
+            }
+            Self::InvalidCoefficients => "Incorrect number of polynomial coefficients".fmt(f),
+            Self::InvalidZeroIdentifier => "Identifier must be nonzero".fmt(f),
+            Self::IncompleteKeyGeneration => "Key generation has not completed".fmt(f),
+            Self::IncorrectNumberOfCommitments => "Too few signing commitments".fmt(f),
+            Self::MissingCommitment => "Signing package has no commitment from the signer".fmt(f),
+            Self::IncorrectCommitment => {
+                "Signing commitments do not match the signer's nonces".fmt(f)
+            }
+            Self::IdentityCommitment => "Signing commitment is the identity".fmt(f),
+            Self::UnknownIdentifier => "Participant is not in the signing package".fmt(f),
+            Self::InvalidSignatureShare { culprit } => {
+                write!(f, "Invalid signature share from participant {culprit:?}")
+            }
+            Self::InvalidSignature => "Invalid aggregated signature".fmt(f),
+        }
+    }
+}
+
+#[cfg(all(feature = "frost", feature = "std"))]
+impl std::error::Error for FrostError {}
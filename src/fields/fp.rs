@@ -5,6 +5,8 @@ use crate::EncodingError;
 
 #[cfg(feature = "arkworks")]
 pub mod arkworks;
+#[cfg(feature = "group")]
+mod ecc;
 mod ops;
 mod u32;
 
@@ -12,6 +14,9 @@ mod u32;
 #[cfg(feature = "arkworks")]
 mod u64;
 
+#[cfg(feature = "group")]
+pub use ecc::FpRepr;
+
 cfg_if! {
     if #[cfg(feature = "arkworks")] {
         pub type Fp = u64::Fp;
@@ -125,7 +130,7 @@ impl Fp {
         if reduced.to_bytes_le() == *bytes {
             Ok(reduced)
         } else {
-            Err(EncodingError::InvalidEncoding)
+            Err(EncodingError::NonCanonicalFieldElement)
         }
     }
 
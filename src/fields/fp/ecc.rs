@@ -0,0 +1,269 @@
+use crate::Fp;
+use crate::fields::fp::{N_8, N_64};
+use elliptic_curve::ff::{FieldBits, PrimeFieldBits};
+use elliptic_curve::{Field, PrimeField, ff};
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+/// The canonical little-endian encoding of an [`Fp`] element.
+///
+/// This wraps a byte array because `PrimeField::Repr` must implement
+/// `Default`, which arrays longer than 32 bytes do not.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FpRepr(pub [u8; N_8]);
+
+impl Default for FpRepr {
+    fn default() -> Self {
+        Self([0u8; N_8])
+    }
+}
+
+impl AsRef<[u8]> for FpRepr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for FpRepr {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl PrimeFieldBits for Fp {
+    #[cfg(target_pointer_width = "64")]
+    type ReprBits = [u64; N_64];
+    #[cfg(target_pointer_width = "32")]
+    type ReprBits = [u32; 2 * N_64];
+
+    #[cfg(target_pointer_width = "64")]
+    fn to_le_bits(&self) -> FieldBits<Self::ReprBits> {
+        FieldBits::new(self.to_le_limbs())
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn to_le_bits(&self) -> FieldBits<Self::ReprBits> {
+        FieldBits::new(split_limbs(self.to_le_limbs()))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    fn char_le_bits() -> FieldBits<Self::ReprBits> {
+        FieldBits::new(Self::MODULUS_LIMBS)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn char_le_bits() -> FieldBits<Self::ReprBits> {
+        FieldBits::new(split_limbs(Self::MODULUS_LIMBS))
+    }
+}
+
+/// Splits 64-bit little-endian limbs into 32-bit ones.
+#[cfg(target_pointer_width = "32")]
+fn split_limbs(limbs: [u64; N_64]) -> [u32; 2 * N_64] {
+    let mut out = [0u32; 2 * N_64];
+    for (i, limb) in limbs.iter().enumerate() {
+        out[2 * i] = *limb as u32;
+        out[2 * i + 1] = (*limb >> 32) as u32;
+    }
+    out
+}
+
+impl Field for Fp {
+    const ZERO: Self = Fp::ZERO;
+    const ONE: Self = Fp::ONE;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let bytes = {
+            let mut out = [0u8; N_8 + 16];
+            rng.fill_bytes(&mut out);
+            out
+        };
+        Self::from_le_bytes_mod_order(&bytes)
+    }
+
+    fn square(&self) -> Self {
+        Fp::square(self)
+    }
+
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        match self.inverse() {
+            Some(value) => CtOption::new(value, Choice::from(1)),
+            None => CtOption::new(Self::default(), Choice::from(0)),
+        }
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // The default implementation goes through `sqrt_ratio`, and the
+        // generic `sqrt_ratio` goes through `sqrt`, so one of them has to be
+        // implemented directly.
+        ff::helpers::sqrt_tonelli_shanks(self, Self::TRACE_MINUS_ONE_DIV_TWO_LIMBS)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for Fp {
+    type Repr = FpRepr;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        match Self::from_bytes_checked(&repr.0) {
+            Ok(value) => CtOption::new(value, Choice::from(1)),
+            Err(_) => CtOption::new(Self::default(), Choice::from(0)),
+        }
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        FpRepr(self.to_bytes_le())
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes_le()[0] & 1)
+    }
+
+    const MODULUS: &'static str = "0x1ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001";
+    const NUM_BITS: u32 = Fp::MODULUS_BIT_SIZE;
+    const CAPACITY: u32 = Fp::MODULUS_BIT_SIZE - 1;
+    const TWO_INV: Self = Self::from_montgomery_limbs([
+        0x8166ffffffffffb4,
+        0x28a04fc1bfffffd8,
+        0xcfbed9d4c53e9ff9,
+        0x3da74bdbb73e3182,
+        0x267a4adfc01e4274,
+        0x0046b330f17efa4d,
+    ]);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::MULTIPLICATIVE_GENERATOR;
+    const S: u32 = Self::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::TWO_ADIC_ROOT_OF_UNITY;
+    const ROOT_OF_UNITY_INV: Self = Self::from_montgomery_limbs([
+        0x8a8ac3c2a47938fd,
+        0x508fe9a085baa767,
+        0xe4953e2bc8fdd496,
+        0xf5887b48f006a8c0,
+        0x929dce71b38f1ff3,
+        0x013eec803167a761,
+    ]);
+    const DELTA: Self = Self::from_montgomery_limbs([
+        0x6c9cfd3970011d5f,
+        0x4b0ad17915e23e4e,
+        0xf54c7d1980b3fc53,
+        0x2ccc49d5f18eb2ae,
+        0x59c6d64b3b1022b9,
+        0x000df8770d21c4e2,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn fp_strategy() -> BoxedStrategy<Fp> {
+        prop::collection::vec(any::<u8>(), N_8)
+            .prop_map(|bytes| Fp::from_le_bytes_mod_order(&bytes))
+            .boxed()
+    }
+
+    #[test]
+    fn constants_are_consistent() {
+        // p - 1 = 2^46 * T, with T odd.
+        assert_eq!(<Fp as PrimeField>::S, 46);
+        assert_eq!(Fp::TRACE_LIMBS[0] & 1, 1);
+        assert_eq!(<Fp as PrimeField>::NUM_BITS, 377);
+        assert_eq!(<Fp as PrimeField>::CAPACITY, 376);
+
+        assert_eq!(
+            <Fp as PrimeField>::MULTIPLICATIVE_GENERATOR,
+            Fp::from(15u64)
+        );
+        assert_eq!(<Fp as PrimeField>::TWO_INV * Fp::from(2u64), Fp::ONE);
+
+        // The generator is a quadratic nonresidue.
+        let g = <Fp as PrimeField>::MULTIPLICATIVE_GENERATOR;
+        assert_eq!(g.pow_vartime(Fp::MODULUS_MINUS_ONE_DIV_TWO_LIMBS), -Fp::ONE);
+
+        // ROOT_OF_UNITY = g^T has order exactly 2^S.
+        let root = <Fp as PrimeField>::ROOT_OF_UNITY;
+        assert_eq!(root, g.pow_vartime(Fp::TRACE_LIMBS));
+        assert_eq!(root * <Fp as PrimeField>::ROOT_OF_UNITY_INV, Fp::ONE);
+        let mut power = root;
+        for _ in 0..45 {
+            power = power.square();
+        }
+        assert_eq!(power, -Fp::ONE);
+        assert_eq!(power.square(), Fp::ONE);
+
+        // DELTA = g^(2^S) has order T.
+        let mut delta = g;
+        for _ in 0..46 {
+            delta = delta.square();
+        }
+        assert_eq!(<Fp as PrimeField>::DELTA, delta);
+        assert_eq!(delta.pow_vartime(Fp::TRACE_LIMBS), Fp::ONE);
+    }
+
+    #[test]
+    fn modulus_matches_limbs() {
+        let hex = <Fp as PrimeField>::MODULUS.trim_start_matches("0x");
+        let mut limbs = [0u64; N_64];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let end = hex.len() - 16 * i;
+            *limb = u64::from_str_radix(&hex[end.saturating_sub(16)..end], 16).unwrap();
+        }
+        assert_eq!(limbs, Fp::MODULUS_LIMBS);
+    }
+
+    #[test]
+    fn sqrt_of_nonresidue_fails() {
+        let g = <Fp as PrimeField>::MULTIPLICATIVE_GENERATOR;
+        assert!(bool::from(Field::sqrt(&g).is_none()));
+
+        let (was_square, root) = Fp::sqrt_ratio(&g, &Fp::ONE);
+        assert!(!bool::from(was_square));
+        assert_eq!(root.square(), g * <Fp as PrimeField>::ROOT_OF_UNITY);
+    }
+
+    proptest! {
+        #[test]
+        fn sqrt_of_square_is_a_root(x in fp_strategy()) {
+            let root = Option::<Fp>::from(Field::sqrt(&x.square())).unwrap();
+            prop_assert!(root == x || root == -x);
+        }
+
+        #[test]
+        fn sqrt_ratio_matches_sqrt(num in fp_strategy(), div in fp_strategy()) {
+            prop_assume!(div != Fp::ZERO);
+            let (was_square, root) = Fp::sqrt_ratio(&num, &div);
+            if bool::from(was_square) {
+                prop_assert_eq!(root.square() * div, num);
+            } else {
+                prop_assert_eq!(root.square() * div, num * <Fp as PrimeField>::ROOT_OF_UNITY);
+            }
+        }
+
+        #[test]
+        fn repr_round_trip(x in fp_strategy()) {
+            prop_assert_eq!(Option::<Fp>::from(Fp::from_repr(x.to_repr())), Some(x));
+            prop_assert_eq!(bool::from(x.is_odd()), x.to_le_limbs()[0] & 1 == 1);
+        }
+
+        #[test]
+        fn bits_match_limbs(x in fp_strategy()) {
+            let bits = x.to_le_bits();
+            let limbs = x.to_le_limbs();
+            for (i, bit) in bits.iter().enumerate() {
+                prop_assert_eq!(*bit, (limbs[i / 64] >> (i % 64)) & 1 == 1);
+            }
+            let modulus = Fp::char_le_bits();
+            for (i, bit) in modulus.iter().enumerate() {
+                prop_assert_eq!(*bit, (Fp::MODULUS_LIMBS[i / 64] >> (i % 64)) & 1 == 1);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 use super::{
     super::{B, N_8, N_32, N_64},
     fiat,
@@ -216,3 +218,16 @@ impl Fp {
         Fp(result)
     }
 }
+
+impl ConditionallySelectable for Fp {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let out = core::array::from_fn(|i| u32::conditional_select(&a.0.0[i], &b.0.0[i], choice));
+        Self(fiat::FpMontgomeryDomainFieldElement(out))
+    }
+}
+
+impl ConstantTimeEq for Fp {
+    fn ct_eq(&self, other: &Fp) -> Choice {
+        self.0.0.ct_eq(&other.0.0)
+    }
+}
@@ -1,6 +1,7 @@
 use ark_bls12_377::Fq as ArkworksFp;
 use ark_ff::{BigInt, Field, PrimeField};
 use ark_serialize::CanonicalSerialize;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::super::{N_8, N_64};
 
@@ -119,3 +120,19 @@ impl Fp {
         Fp(-self.0)
     }
 }
+
+impl ConditionallySelectable for Fp {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let a_limbs = a.0.0.0;
+        let b_limbs = b.0.0.0;
+        let out =
+            core::array::from_fn(|i| u64::conditional_select(&a_limbs[i], &b_limbs[i], choice));
+        Self::from_montgomery_limbs(out)
+    }
+}
+
+impl ConstantTimeEq for Fp {
+    fn ct_eq(&self, other: &Fp) -> Choice {
+        self.0.0.0.ct_eq(&other.0.0.0)
+    }
+}
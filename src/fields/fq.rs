@@ -1,3 +1,6 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use cfg_if::cfg_if;
 use rand_core::CryptoRngCore;
 
@@ -5,7 +8,10 @@ use crate::EncodingError;
 
 #[cfg(feature = "arkworks")]
 pub mod arkworks;
+#[cfg(feature = "group")]
+mod ecc;
 mod ops;
+mod sqrt;
 mod u32;
 
 // The u64 backend requires arkworks
@@ -110,7 +116,7 @@ impl Fq {
         if reduced.to_bytes_le() == *bytes {
             Ok(reduced)
         } else {
-            Err(EncodingError::InvalidEncoding)
+            Err(EncodingError::NonCanonicalFieldElement)
         }
     }
 
@@ -140,6 +146,34 @@ impl Fq {
         }
         res
     }
+
+    /// Inverts every element of `values` in place, using a single field
+    /// inversion for the whole slice.
+    ///
+    /// Zero elements are left as zero. This branches on which elements are
+    /// zero, so it should only be used on public values.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn batch_inverse(values: &mut [Fq]) {
+        // Montgomery's trick: scratch[i] holds the product of the nonzero
+        // values before index i.
+        let mut scratch = Vec::with_capacity(values.len());
+        let mut acc = Fq::ONE;
+        for value in values.iter() {
+            scratch.push(acc);
+            if *value != Fq::ZERO {
+                acc *= value;
+            }
+        }
+
+        let mut acc = acc.inverse().expect("product of nonzero values is nonzero");
+        for (value, before) in values.iter_mut().rev().zip(scratch.into_iter().rev()) {
+            if *value != Fq::ZERO {
+                let inverse = acc * before;
+                acc *= *value;
+                *value = inverse;
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -151,4 +185,23 @@ mod test {
         assert_eq!(Fq::from_bytes_checked(&[0; N_8]), Ok(Fq::ZERO));
         assert!(Fq::from_bytes_checked(&[0xFF; N_8]).is_err());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_batch_inverse() {
+        let values = [
+            Fq::from(2u64),
+            Fq::ZERO,
+            Fq::from(3u64),
+            Fq::from(5u64),
+            Fq::ZERO,
+        ];
+        let mut inverses = values;
+        Fq::batch_inverse(&mut inverses);
+        for (value, inverse) in values.iter().zip(inverses.iter()) {
+            assert_eq!(*inverse, value.inverse().unwrap_or(Fq::ZERO));
+        }
+
+        Fq::batch_inverse(&mut []);
+    }
 }
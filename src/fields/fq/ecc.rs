@@ -0,0 +1,239 @@
+use crate::Fq;
+use crate::fields::fq::{N_8, N_64};
+use elliptic_curve::ff::{FieldBits, PrimeFieldBits};
+use elliptic_curve::{Field, PrimeField, ff};
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+impl PrimeFieldBits for Fq {
+    #[cfg(target_pointer_width = "64")]
+    type ReprBits = [u64; N_64];
+    #[cfg(target_pointer_width = "32")]
+    type ReprBits = [u32; 2 * N_64];
+
+    #[cfg(target_pointer_width = "64")]
+    fn to_le_bits(&self) -> FieldBits<Self::ReprBits> {
+        FieldBits::new(self.to_le_limbs())
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn to_le_bits(&self) -> FieldBits<Self::ReprBits> {
+        FieldBits::new(split_limbs(self.to_le_limbs()))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    fn char_le_bits() -> FieldBits<Self::ReprBits> {
+        FieldBits::new(Self::MODULUS_LIMBS)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn char_le_bits() -> FieldBits<Self::ReprBits> {
+        FieldBits::new(split_limbs(Self::MODULUS_LIMBS))
+    }
+}
+
+/// Splits 64-bit little-endian limbs into 32-bit ones.
+#[cfg(target_pointer_width = "32")]
+fn split_limbs(limbs: [u64; N_64]) -> [u32; 2 * N_64] {
+    let mut out = [0u32; 2 * N_64];
+    for (i, limb) in limbs.iter().enumerate() {
+        out[2 * i] = *limb as u32;
+        out[2 * i + 1] = (*limb >> 32) as u32;
+    }
+    out
+}
+
+impl Field for Fq {
+    const ZERO: Self = Fq::ZERO;
+    const ONE: Self = Fq::ONE;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let bytes = {
+            let mut out = [0u8; N_8 + 16];
+            rng.fill_bytes(&mut out);
+            out
+        };
+        Self::from_le_bytes_mod_order(&bytes)
+    }
+
+    fn square(&self) -> Self {
+        Fq::square(self)
+    }
+
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        match self.inverse() {
+            Some(value) => CtOption::new(value, Choice::from(1)),
+            None => CtOption::new(Self::default(), Choice::from(0)),
+        }
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // The default implementation goes through `sqrt_ratio`, and the
+        // generic `sqrt_ratio` goes through `sqrt`, so one of them has to be
+        // implemented directly.
+        ff::helpers::sqrt_tonelli_shanks(self, Self::TRACE_MINUS_ONE_DIV_TWO_LIMBS)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for Fq {
+    type Repr = [u8; N_8];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        match Self::from_bytes_checked(&repr) {
+            Ok(value) => CtOption::new(value, Choice::from(1)),
+            Err(_) => CtOption::new(Self::default(), Choice::from(0)),
+        }
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes_le()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes_le()[0] & 1)
+    }
+
+    const MODULUS: &'static str =
+        "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001";
+    const NUM_BITS: u32 = Fq::MODULUS_BIT_SIZE;
+    const CAPACITY: u32 = Fq::MODULUS_BIT_SIZE - 1;
+    const TWO_INV: Self = Self::from_montgomery_limbs([
+        0xc396fffffffffffa,
+        0xe60136071ffffff9,
+        0xbbc63149d6b1dff7,
+        0x0ffb9fc862f41ff9,
+    ]);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::MULTIPLICATIVE_GENERATOR;
+    const S: u32 = Self::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::TWO_ADIC_ROOT_OF_UNITY;
+    const ROOT_OF_UNITY_INV: Self = Self::from_montgomery_limbs([
+        0x0d248e974767f5bd,
+        0xfa72032b32f67f4c,
+        0x7ec7e591ee4ee58f,
+        0x1227d66f8e126f27,
+    ]);
+    const DELTA: Self = Self::from_montgomery_limbs([
+        0xc81be8416710aa44,
+        0xaaaff6305effee12,
+        0x5af037f7d8551509,
+        0x0f34eacc4d456e15,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn fq_strategy() -> BoxedStrategy<Fq> {
+        any::<[u8; 32]>()
+            .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+            .boxed()
+    }
+
+    #[test]
+    fn constants_are_consistent() {
+        // q - 1 = 2^47 * T, with T odd.
+        assert_eq!(<Fq as PrimeField>::S, 47);
+        assert_eq!(Fq::TRACE_LIMBS[0] & 1, 1);
+        assert_eq!(<Fq as PrimeField>::NUM_BITS, 253);
+        assert_eq!(<Fq as PrimeField>::CAPACITY, 252);
+
+        assert_eq!(
+            <Fq as PrimeField>::MULTIPLICATIVE_GENERATOR,
+            Fq::from(22u64)
+        );
+        assert_eq!(<Fq as PrimeField>::TWO_INV * Fq::from(2u64), Fq::ONE);
+
+        // The generator is a quadratic nonresidue.
+        let g = <Fq as PrimeField>::MULTIPLICATIVE_GENERATOR;
+        assert_eq!(g.pow_vartime(Fq::MODULUS_MINUS_ONE_DIV_TWO_LIMBS), -Fq::ONE);
+
+        // ROOT_OF_UNITY = g^T has order exactly 2^S.
+        let root = <Fq as PrimeField>::ROOT_OF_UNITY;
+        assert_eq!(root, g.pow_vartime(Fq::TRACE_LIMBS));
+        assert_eq!(root * <Fq as PrimeField>::ROOT_OF_UNITY_INV, Fq::ONE);
+        let mut power = root;
+        for _ in 0..46 {
+            power = power.square();
+        }
+        assert_eq!(power, -Fq::ONE);
+        assert_eq!(power.square(), Fq::ONE);
+
+        // DELTA = g^(2^S) has order T.
+        let mut delta = g;
+        for _ in 0..47 {
+            delta = delta.square();
+        }
+        assert_eq!(<Fq as PrimeField>::DELTA, delta);
+        assert_eq!(delta.pow_vartime(Fq::TRACE_LIMBS), Fq::ONE);
+    }
+
+    #[test]
+    fn modulus_matches_limbs() {
+        let hex = <Fq as PrimeField>::MODULUS.trim_start_matches("0x");
+        let mut limbs = [0u64; N_64];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let end = hex.len() - 16 * i;
+            *limb = u64::from_str_radix(&hex[end.saturating_sub(16)..end], 16).unwrap();
+        }
+        assert_eq!(limbs, Fq::MODULUS_LIMBS);
+    }
+
+    #[test]
+    fn sqrt_of_nonresidue_fails() {
+        let g = <Fq as PrimeField>::MULTIPLICATIVE_GENERATOR;
+        assert!(bool::from(Field::sqrt(&g).is_none()));
+
+        let (was_square, root) = Fq::sqrt_ratio(&g, &Fq::ONE);
+        assert!(!bool::from(was_square));
+        assert_eq!(root.square(), g * <Fq as PrimeField>::ROOT_OF_UNITY);
+    }
+
+    proptest! {
+        #[test]
+        fn sqrt_of_square_is_a_root(x in fq_strategy()) {
+            let root = Option::<Fq>::from(Field::sqrt(&x.square())).unwrap();
+            prop_assert!(root == x || root == -x);
+        }
+
+        #[test]
+        fn sqrt_ratio_matches_sqrt(num in fq_strategy(), div in fq_strategy()) {
+            prop_assume!(div != Fq::ZERO);
+            let (was_square, root) = Fq::sqrt_ratio(&num, &div);
+            if bool::from(was_square) {
+                prop_assert_eq!(root.square() * div, num);
+            } else {
+                prop_assert_eq!(root.square() * div, num * <Fq as PrimeField>::ROOT_OF_UNITY);
+            }
+        }
+
+        #[test]
+        fn repr_round_trip(x in fq_strategy()) {
+            prop_assert_eq!(Option::<Fq>::from(Fq::from_repr(x.to_repr())), Some(x));
+            prop_assert_eq!(bool::from(x.is_odd()), x.to_le_limbs()[0] & 1 == 1);
+        }
+
+        #[test]
+        fn bits_match_limbs(x in fq_strategy()) {
+            let bits = x.to_le_bits();
+            let limbs = x.to_le_limbs();
+            for (i, bit) in bits.iter().enumerate() {
+                prop_assert_eq!(*bit, (limbs[i / 64] >> (i % 64)) & 1 == 1);
+            }
+            let modulus = Fq::char_le_bits();
+            for (i, bit) in modulus.iter().enumerate() {
+                prop_assert_eq!(*bit, (Fq::MODULUS_LIMBS[i / 64] >> (i % 64)) & 1 == 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Constant-time square roots of ratios, shared by both field backends.
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::Fq;
+
+/// `ZETA^TRACE`, where `ZETA` is the nonsquare used by decaf377 and `TRACE`
+/// is the odd part of `q - 1`.
+const ZETA_TO_TRACE: Fq = Fq::from_montgomery_limbs([
+    6282505393754313363,
+    14378628227555923904,
+    9804873068900332207,
+    302335131180501866,
+]);
+
+/// `ZETA^((TRACE + 1) / 2)`.
+const ZETA_TO_TRACE_PLUS_ONE_DIV_TWO: Fq = Fq::from_montgomery_limbs([
+    9228542452838733896,
+    1659757420279635529,
+    5601448727074663207,
+    65302780762596835,
+]);
+
+impl Fq {
+    /// Raises this element to the power given by the little-endian limbs
+    /// `limbs`.
+    ///
+    /// The running time depends on the exponent, which must be public, but
+    /// not on the base.
+    pub(crate) fn pow_le_limbs(&self, limbs: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        let mut insert = *self;
+        for limb in limbs {
+            for i in 0..64 {
+                if (limb >> i) & 1 == 1 {
+                    acc *= insert;
+                }
+                insert *= insert;
+            }
+        }
+        acc
+    }
+
+    /// Squares this element `k` times.
+    fn square_times(&self, k: u32) -> Self {
+        let mut acc = *self;
+        for _ in 0..k {
+            acc = acc.square();
+        }
+        acc
+    }
+
+    /// Computes the square root of a ratio of field elements in constant time,
+    /// returning:
+    ///
+    /// - `(1, sqrt(num/den))` if `num` and `den` are both nonzero and `num/den` is square;
+    /// - `(1, 0)` if `num` is zero;
+    /// - `(0, 0)` if `den` is zero;
+    /// - `(0, sqrt(zeta*num/den))` if `num` and `den` are both nonzero and `num/den` is nonsquare;
+    ///
+    /// This matches the interface of `sqrt_ratio_zeta`, except that the sign
+    /// of the returned root may differ, and that the running time does not
+    /// depend on the inputs.
+    pub fn sqrt_ratio_zeta_ct(num: &Self, den: &Self) -> (Choice, Self) {
+        // This is the constant-time `sqrt_ratio` from Appendix F.2.1.1 of
+        // RFC 9380, with Z = ZETA and the following constants:
+        //
+        // c1 = TWO_ADICITY
+        // c2 = TRACE
+        // c3 = (c2 - 1) / 2
+        // c4 = 2^c1 - 1
+        // c5 = 2^(c1 - 1)
+        // c6 = ZETA^c2
+        // c7 = ZETA^((c2 + 1) / 2)
+        let c4 = [(1u64 << Self::TWO_ADICITY) - 1];
+
+        let mut tv1 = ZETA_TO_TRACE;
+        let mut tv2 = den.pow_le_limbs(&c4);
+        let mut tv3 = tv2.square();
+        tv3 *= den;
+        let mut tv5 = *num * tv3;
+        tv5 = tv5.pow_le_limbs(&Self::TRACE_MINUS_ONE_DIV_TWO_LIMBS);
+        tv5 *= tv2;
+        tv2 = tv5 * den;
+        tv3 = tv5 * num;
+        let mut tv4 = tv3 * tv2;
+        tv5 = tv4.square_times(Self::TWO_ADICITY - 1);
+        let is_qr = tv5.ct_eq(&Self::ONE);
+        tv2 = tv3 * ZETA_TO_TRACE_PLUS_ONE_DIV_TWO;
+        tv5 = tv4 * tv1;
+        tv3 = Self::conditional_select(&tv2, &tv3, is_qr);
+        tv4 = Self::conditional_select(&tv5, &tv4, is_qr);
+
+        for i in (2..=Self::TWO_ADICITY).rev() {
+            tv5 = tv4.square_times(i - 2);
+            let e1 = tv5.ct_eq(&Self::ONE);
+            tv2 = tv3 * tv1;
+            tv1 = tv1.square();
+            tv5 = tv4 * tv1;
+            tv3 = Self::conditional_select(&tv2, &tv3, e1);
+            tv4 = Self::conditional_select(&tv5, &tv4, e1);
+        }
+
+        // The procedure above reports zero as a nonsquare, while we want
+        // `num = 0` to count as square; `den = 0` already gives `(0, 0)`.
+        let num_is_zero = num.ct_eq(&Self::ZERO);
+
+        (is_qr | num_is_zero, tv3)
+    }
+}
+
+#[cfg(all(test, feature = "arkworks"))]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    use crate::ZETA;
+
+    fn fq_strategy() -> BoxedStrategy<Fq> {
+        any::<[u8; 32]>()
+            .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+            .boxed()
+    }
+
+    #[test]
+    fn sqrt_constants_match_zeta() {
+        assert_eq!(ZETA.pow_le_limbs(&Fq::TRACE_LIMBS), ZETA_TO_TRACE);
+
+        let mut trace_plus_one_div_two = Fq::TRACE_MINUS_ONE_DIV_TWO_LIMBS;
+        trace_plus_one_div_two[0] += 1;
+        assert_eq!(
+            ZETA.pow_le_limbs(&trace_plus_one_div_two),
+            ZETA_TO_TRACE_PLUS_ONE_DIV_TWO
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn sqrt_ratio_zeta_ct_matches_vartime(u in fq_strategy(), v in fq_strategy()) {
+            let (was_square, root) = Fq::sqrt_ratio_zeta(&u, &v);
+            let (ct_was_square, ct_root) = Fq::sqrt_ratio_zeta_ct(&u, &v);
+            assert_eq!(was_square, bool::from(ct_was_square));
+            assert!(ct_root == root || ct_root == -root);
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_zeta_ct_edge_cases() {
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::ZERO, &Fq::ONE);
+        assert!(bool::from(was_square));
+        assert_eq!(root, Fq::ZERO);
+
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &Fq::ZERO);
+        assert!(!bool::from(was_square));
+        assert_eq!(root, Fq::ZERO);
+
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &ZETA);
+        assert!(!bool::from(was_square));
+        assert_eq!(root.square(), Fq::ONE);
+    }
+
+    #[test]
+    fn sqrt_ratio_zeta_ct_of_equal_inputs() {
+        // The case recorded in proptest-regressions/fields/fq/sqrt.txt,
+        // u = v = 2^248, whose ratio is one.
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        let u = Fq::from_le_bytes_mod_order(&bytes);
+
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&u, &u);
+        assert!(bool::from(was_square));
+        assert!(root == Fq::ONE || root == -Fq::ONE);
+        assert!(Fq::sqrt_ratio_zeta(&u, &u).0);
+    }
+}
@@ -5,9 +5,10 @@ use crate::EncodingError;
 
 #[cfg(feature = "arkworks")]
 pub mod arkworks;
-#[cfg(feature = "ecc-group")]
+#[cfg(feature = "group")]
 mod ecc;
 mod ops;
+mod recode;
 pub mod u32;
 
 // The u64 backend requires arkworks
@@ -106,7 +107,7 @@ impl Fr {
         if reduced.to_bytes_le() == *bytes {
             Ok(reduced)
         } else {
-            Err(EncodingError::InvalidEncoding)
+            Err(EncodingError::NonCanonicalFieldElement)
         }
     }
 
@@ -1,5 +1,5 @@
 use crate::Fr;
-use crate::fields::fr::{N_8, N_64};
+use crate::fields::fr::N_8;
 use elliptic_curve::ff::{FieldBits, PrimeFieldBits};
 use elliptic_curve::{Field, PrimeField, ff};
 use rand_core::RngCore;
@@ -66,11 +66,11 @@ impl Field for Fr {
     }
 
     fn square(&self) -> Self {
-        <Self as ark_ff::Field>::square(self)
+        Fr::square(self)
     }
 
     fn double(&self) -> Self {
-        <Self as ark_ff::Field>::double(self)
+        *self + self
     }
 
     fn invert(&self) -> CtOption<Self> {
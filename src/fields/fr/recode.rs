@@ -0,0 +1,176 @@
+//! Signed-digit recodings of scalars, used by the scalar multiplication
+//! algorithms.
+//!
+//! These follow the recodings used by `curve25519-dalek`, adapted to the
+//! 251-bit `Fr` modulus: since the top five bits of a reduced scalar are
+//! always zero, the final carry never overflows the top digit.
+
+use crate::Fr;
+
+impl Fr {
+    /// Returns the number of signed radix-`2^w` digits produced by
+    /// [`Fr::as_radix_2w`].
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn radix_2w_digit_count(w: usize) -> usize {
+        256usize.div_ceil(w)
+    }
+
+    /// Writes this scalar in signed radix `2^w`, for `4 <= w <= 8`.
+    ///
+    /// The output coefficients `a_i` satisfy
+    /// $$
+    /// s = a\_0 + a\_1 2^w + \cdots + a\_{n-1} 2^{w(n-1)},
+    /// $$
+    /// with $-2^{w-1} \leq a\_i < 2^{w-1}$ for every $i$, where `n` is
+    /// [`Fr::radix_2w_digit_count`]. Unused trailing digits are zero.
+    ///
+    /// The running time depends only on `w`, not on the value of the scalar.
+    pub(crate) fn as_radix_2w(&self, w: usize) -> [i8; 64] {
+        debug_assert!((4..=8).contains(&w));
+
+        // Pad with a zero limb so that windows straddling the top limb can
+        // always read the following one.
+        let mut limbs = [0u64; 5];
+        limbs[..4].copy_from_slice(&self.to_le_limbs());
+
+        let radix: u64 = 1 << w;
+        let window_mask: u64 = radix - 1;
+
+        let mut carry = 0u64;
+        let mut digits = [0i8; 64];
+        let digits_count = 256usize.div_ceil(w);
+        for (i, digit) in digits.iter_mut().take(digits_count).enumerate() {
+            // Construct a buffer of bits of the scalar, starting at `bit_offset`.
+            let bit_offset = i * w;
+            let u64_idx = bit_offset / 64;
+            let bit_idx = bit_offset % 64;
+
+            let bit_buf = if bit_idx < 64 - w {
+                limbs[u64_idx] >> bit_idx
+            } else {
+                (limbs[u64_idx] >> bit_idx) | (limbs[u64_idx + 1] << (64 - bit_idx))
+            };
+
+            // Recenter coefficients from [0, 2^w) to [-2^w/2, 2^w/2).
+            let coef = carry + (bit_buf & window_mask);
+            carry = (coef + (radix / 2)) >> w;
+            *digit = ((coef as i64) - (carry << w) as i64) as i8;
+        }
+
+        debug_assert_eq!(carry, 0, "reduced scalars never carry out of the top digit");
+
+        digits
+    }
+
+    /// Writes this scalar in signed radix `16`, with digits in `[-8, 8)`.
+    pub(crate) fn as_radix_16(&self) -> [i8; 64] {
+        self.as_radix_2w(4)
+    }
+
+    /// Computes the width-`w` non-adjacent form of this scalar, for
+    /// `2 <= w <= 8`.
+    ///
+    /// Every nonzero digit is odd and bounded by $|a\_i| < 2^{w-1}$, and any
+    /// `w` consecutive digits contain at most one nonzero digit.
+    ///
+    /// This recoding is variable-time, and must only be used on public scalars.
+    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+        debug_assert!((2..=8).contains(&w));
+
+        let mut naf = [0i8; 256];
+
+        let mut limbs = [0u64; 5];
+        limbs[..4].copy_from_slice(&self.to_le_limbs());
+
+        let width = 1 << w;
+        let window_mask = width - 1;
+
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < 256 {
+            // Construct a buffer of bits of the scalar, starting at bit `pos`.
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+            let bit_buf: u64 = if bit_idx < 64 - w {
+                limbs[u64_idx] >> bit_idx
+            } else {
+                (limbs[u64_idx] >> bit_idx) | (limbs[1 + u64_idx] << (64 - bit_idx))
+            };
+
+            // Add the carry into the current window.
+            let window = carry + (bit_buf & window_mask);
+
+            if window & 1 == 0 {
+                // If the window value is even, the current bit is zero, so
+                // we can just move on to the next one.
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i8).wrapping_sub(width as i8);
+            }
+
+            pos += w;
+        }
+
+        naf
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn fr_strategy() -> BoxedStrategy<Fr> {
+        any::<[u8; 32]>()
+            .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+            .boxed()
+    }
+
+    /// Reassembles `sum_i digits[i] * base^i` in the scalar field.
+    fn recombine(digits: &[i8], base: Fr) -> Fr {
+        digits.iter().rev().fold(Fr::ZERO, |acc, &digit| {
+            let magnitude = Fr::from(digit.unsigned_abs());
+            let digit = if digit < 0 { -magnitude } else { magnitude };
+            acc * base + digit
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn radix_2w_recombines(s in fr_strategy(), w in 4usize..=8) {
+            let digits = s.as_radix_2w(w);
+            let bound = 1i16 << (w - 1);
+            for digit in &digits[..Fr::radix_2w_digit_count(w)] {
+                prop_assert!(-bound <= *digit as i16 && (*digit as i16) < bound);
+            }
+            for digit in &digits[Fr::radix_2w_digit_count(w)..] {
+                prop_assert_eq!(*digit, 0);
+            }
+            prop_assert_eq!(recombine(&digits, Fr::from(1u64 << w)), s);
+        }
+
+        #[test]
+        fn naf_recombines(s in fr_strategy(), w in 2usize..=8) {
+            let naf = s.non_adjacent_form(w);
+            let bound = 1i16 << (w - 1);
+            for (i, digit) in naf.iter().enumerate() {
+                if *digit != 0 {
+                    prop_assert!(digit % 2 != 0);
+                    prop_assert!((*digit as i16).abs() < bound);
+                    for other in naf.iter().skip(i + 1).take(w - 1) {
+                        prop_assert_eq!(*other, 0);
+                    }
+                }
+            }
+            prop_assert_eq!(recombine(&naf, Fr::from(2u64)), s);
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! FROST threshold Schnorr signatures over decaf377, following [RFC 9591].
+//!
+//! A group signing key is split among `n` participants so that any `t` of
+//! them can jointly sign. The result is an ordinary [`Signature`], which
+//! verifies under the group's [`VerificationKey`] exactly as if a single
+//! signer had made it.
+//!
+//! Signing takes two rounds. Each signer first generates nonces and
+//! publishes their commitments ([`round1::commit`]). A coordinator collects
+//! the commitments and the message into a [`SigningPackage`], from which
+//! each signer produces a signature share ([`round2::sign`]). Finally the
+//! coordinator combines the shares with [`aggregate`], which identifies the
+//! culprit if any share is invalid.
+//!
+//! Keys can be generated by a trusted dealer with
+//! [`keys::generate_with_dealer`], or from a given secret with
+//! [`keys::split`]. With the `ecc-group` feature, they can also be generated
+//! without a dealer by a `frost-dkg` distributed key generation, whose
+//! participants collect their keys with `KeyPackage::from_participant` and
+//! `PublicKeyPackage::from_participant`.
+//!
+//! # Ciphersuite
+//!
+//! The context string is `FROST-DECAF377-BLAKE2B512-v1`. Elements are
+//! serialized with [`Element::vartime_compress`] and scalars with
+//! [`Fr::to_bytes`]. Each hash is Blake2b-512 of a domain separator followed
+//! by its input, where the domain separator is the context string followed
+//! by a tag:
+//!
+//! - `H1` uses the tag `rho` and `H3` the tag `nonce`; their outputs are
+//!   read in little-endian order and reduced modulo the group order;
+//! - `H4` uses the tag `msg` and `H5` the tag `com`;
+//! - `H2` is the challenge hash of the [`signature`](crate::signature)
+//!   module, so that aggregated signatures verify as single-party ones.
+//!
+//! [RFC 9591]: https://www.rfc-editor.org/rfc/rfc9591.html
+
+#![allow(non_snake_case)]
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use blake2::{Blake2b512, Digest};
+
+use crate::interpolation::lagrange_coefficient;
+use crate::signature::{Signature, VerificationKey, challenge};
+use crate::{Element, Fr, FrostError};
+
+pub mod keys;
+pub mod round1;
+pub mod round2;
+
+pub use keys::{KeyPackage, PublicKeyPackage, SigningShare};
+
+/// The context string of the ciphersuite.
+pub const CONTEXT_STRING: &str = "FROST-DECAF377-BLAKE2B512-v1";
+
+/// Hashes the context string, `tag` and then `parts` with Blake2b-512.
+fn hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(CONTEXT_STRING.as_bytes());
+    hasher.update(tag);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Derives binding factors.
+fn H1(parts: &[&[u8]]) -> Fr {
+    Fr::from_le_bytes_mod_order(&hash(b"rho", parts))
+}
+
+/// Derives nonces.
+fn H3(parts: &[&[u8]]) -> Fr {
+    Fr::from_le_bytes_mod_order(&hash(b"nonce", parts))
+}
+
+/// Hashes the message.
+fn H4(msg: &[u8]) -> [u8; 64] {
+    hash(b"msg", &[msg])
+}
+
+/// Hashes the encoded commitment list.
+fn H5(encoded_commitments: &[u8]) -> [u8; 64] {
+    hash(b"com", &[encoded_commitments])
+}
+
+/// The identifier of a participant: a nonzero scalar, which is the point at
+/// which the participant's share of the secret polynomial is evaluated.
+///
+/// Identifiers are ordered as integers, as RFC 9591 requires for the
+/// commitment list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Identifier(Fr);
+
+impl Identifier {
+    /// Creates an identifier from a scalar, which must be nonzero.
+    pub fn new(scalar: Fr) -> Result<Identifier, FrostError> {
+        if scalar == Fr::ZERO {
+            Err(FrostError::InvalidZeroIdentifier)
+        } else {
+            Ok(Identifier(scalar))
+        }
+    }
+
+    /// Returns the scalar value of this identifier.
+    pub fn to_scalar(&self) -> Fr {
+        self.0
+    }
+
+    /// Returns the encoding of this identifier as a scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl TryFrom<u16> for Identifier {
+    type Error = FrostError;
+
+    fn try_from(n: u16) -> Result<Self, Self::Error> {
+        Identifier::new(Fr::from(n))
+    }
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the little-endian encodings from the most significant byte.
+        self.to_bytes()
+            .iter()
+            .rev()
+            .cmp(other.to_bytes().iter().rev())
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The commitments of the signers and the message to sign, which the
+/// coordinator sends to every signer in the second round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPackage {
+    commitments: BTreeMap<Identifier, round1::SigningCommitments>,
+    message: Vec<u8>,
+}
+
+/// The values derived from a signing package that every participant needs.
+struct SigningContext {
+    binding_factors: BTreeMap<Identifier, Fr>,
+    group_commitment: Element,
+    challenge: Fr,
+}
+
+impl SigningPackage {
+    /// Creates a signing package from the commitments of each signer.
+    pub fn new(
+        commitments: BTreeMap<Identifier, round1::SigningCommitments>,
+        message: &[u8],
+    ) -> SigningPackage {
+        SigningPackage {
+            commitments,
+            message: message.to_vec(),
+        }
+    }
+
+    /// Returns the commitments of each signer.
+    pub fn commitments(&self) -> &BTreeMap<Identifier, round1::SigningCommitments> {
+        &self.commitments
+    }
+
+    /// Returns the message to sign.
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// Computes the binding factor of each signer under `verifying_key`,
+    /// as `H1(PK || H4(msg) || H5(commitment list) || identifier)`.
+    pub fn binding_factors(&self, verifying_key: &VerificationKey) -> BTreeMap<Identifier, Fr> {
+        let mut encoded_commitments = Vec::with_capacity(96 * self.commitments.len());
+        for (identifier, commitments) in &self.commitments {
+            encoded_commitments.extend_from_slice(&identifier.to_bytes());
+            encoded_commitments.extend_from_slice(&commitments.to_bytes());
+        }
+
+        let msg_hash = H4(&self.message);
+        let commitment_hash = H5(&encoded_commitments);
+        self.commitments
+            .keys()
+            .map(|identifier| {
+                let rho = H1(&[
+                    &verifying_key.to_bytes(),
+                    &msg_hash,
+                    &commitment_hash,
+                    &identifier.to_bytes(),
+                ]);
+                (*identifier, rho)
+            })
+            .collect()
+    }
+
+    fn context(&self, verifying_key: &VerificationKey) -> Result<SigningContext, FrostError> {
+        let binding_factors = self.binding_factors(verifying_key);
+
+        let mut group_commitment = Element::IDENTITY;
+        for (identifier, commitments) in &self.commitments {
+            if commitments.hiding().is_identity() || commitments.binding().is_identity() {
+                return Err(FrostError::IdentityCommitment);
+            }
+            group_commitment +=
+                commitments.hiding() + commitments.binding() * binding_factors[identifier];
+        }
+
+        let challenge = challenge(
+            &group_commitment.vartime_compress(),
+            &verifying_key.bytes,
+            &self.message,
+        );
+
+        Ok(SigningContext {
+            binding_factors,
+            group_commitment,
+            challenge,
+        })
+    }
+
+    /// Checks a signature share against the commitments of its signer.
+    fn verify_share(
+        &self,
+        context: &SigningContext,
+        identifier: &Identifier,
+        verifying_share: &Element,
+        share: &round2::SignatureShare,
+    ) -> Result<(), FrostError> {
+        let commitments = self
+            .commitments
+            .get(identifier)
+            .ok_or(FrostError::UnknownIdentifier)?;
+        let rho = context.binding_factors[identifier];
+        let lambda = lagrange_coefficient(
+            &identifier.to_scalar(),
+            self.commitments.keys().map(Identifier::to_scalar),
+        );
+
+        // z_i * B - (c * lambda_i) * Y_i = D_i + rho_i * E_i
+        let lhs = Element::vartime_double_scalar_mul_basepoint(
+            &-(context.challenge * lambda),
+            verifying_share,
+            &share.0,
+        );
+        if lhs == commitments.hiding() + commitments.binding() * rho {
+            Ok(())
+        } else {
+            Err(FrostError::InvalidSignatureShare {
+                culprit: *identifier,
+            })
+        }
+    }
+}
+
+/// Checks the signature share of `identifier`, whose verifying share is
+/// `verifying_share`, so that the coordinator can identify a misbehaving
+/// signer.
+pub fn verify_signature_share(
+    identifier: &Identifier,
+    verifying_share: &Element,
+    share: &round2::SignatureShare,
+    signing_package: &SigningPackage,
+    verifying_key: &VerificationKey,
+) -> Result<(), FrostError> {
+    let context = signing_package.context(verifying_key)?;
+    signing_package.verify_share(&context, identifier, verifying_share, share)
+}
+
+/// Combines the signature shares of every signer in `signing_package` into
+/// a signature under the group verification key.
+///
+/// The signature is verified before it is returned. If it is invalid, each
+/// share is checked, and the first invalid one is reported as the culprit.
+pub fn aggregate(
+    signing_package: &SigningPackage,
+    shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &PublicKeyPackage,
+) -> Result<Signature, FrostError> {
+    if !shares.keys().eq(signing_package.commitments.keys()) {
+        return Err(FrostError::UnknownIdentifier);
+    }
+
+    let verifying_key = pubkeys.verifying_key();
+    let context = signing_package.context(verifying_key)?;
+    let signature = Signature {
+        R: context.group_commitment.vartime_compress(),
+        s: shares.values().map(|share| share.0).sum(),
+    };
+
+    if verifying_key
+        .verify(&signing_package.message, &signature)
+        .is_ok()
+    {
+        return Ok(signature);
+    }
+
+    for (identifier, share) in shares {
+        let verifying_share = pubkeys
+            .verifying_shares()
+            .get(identifier)
+            .ok_or(FrostError::UnknownIdentifier)?;
+        signing_package.verify_share(&context, identifier, verifying_share, share)?;
+    }
+    Err(FrostError::InvalidSignature)
+}
@@ -0,0 +1,275 @@
+//! Key material for FROST, and its generation by a trusted dealer or a
+//! distributed key generation.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+#[cfg(feature = "ecc-group")]
+use frost_dkg::{Participant, ParticipantImpl};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+#[cfg(feature = "ecc-group")]
+use crate::dkg;
+use crate::frost::Identifier;
+use crate::signature::VerificationKey;
+use crate::{Element, EncodingError, Fr, FrostError};
+
+/// A participant's share of the group signing key, which is zeroed when
+/// dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SigningShare(pub(crate) Fr);
+
+impl SigningShare {
+    /// Returns the encoding of this share.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Returns the verifying share `s_i * B` for this share.
+    pub fn verifying_share(&self) -> Element {
+        Element::basepoint_mul_ct(&self.0)
+    }
+}
+
+impl From<Fr> for SigningShare {
+    fn from(scalar: Fr) -> SigningShare {
+        SigningShare(scalar)
+    }
+}
+
+impl TryFrom<[u8; 32]> for SigningShare {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(SigningShare(Fr::from_bytes_checked(&bytes)?))
+    }
+}
+
+impl core::fmt::Debug for SigningShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SigningShare(..)")
+    }
+}
+
+impl Drop for SigningShare {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Everything a participant needs to sign: their identifier and signing
+/// share, along with the group verification key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPackage {
+    identifier: Identifier,
+    signing_share: SigningShare,
+    verifying_share: Element,
+    verifying_key: VerificationKey,
+    min_signers: u16,
+}
+
+impl KeyPackage {
+    /// Creates a key package, computing the verifying share from the
+    /// signing share.
+    pub fn new(
+        identifier: Identifier,
+        signing_share: SigningShare,
+        verifying_key: VerificationKey,
+        min_signers: u16,
+    ) -> KeyPackage {
+        KeyPackage {
+            identifier,
+            verifying_share: signing_share.verifying_share(),
+            signing_share,
+            verifying_key,
+            min_signers,
+        }
+    }
+
+    /// Collects the key package of a participant that has completed a
+    /// `frost-dkg` key generation, which must use [`Element::GENERATOR`] as
+    /// its message generator.
+    ///
+    /// The group verification key is the sum of the constant terms of the
+    /// Feldman commitments of every participant whose shares were used, and
+    /// the number of signers needed is the threshold of the key generation.
+    #[cfg(feature = "ecc-group")]
+    pub fn from_participant<I>(
+        participant: &Participant<I, Element>,
+    ) -> Result<KeyPackage, FrostError>
+    where
+        I: ParticipantImpl<Element> + Default,
+    {
+        let commitments =
+            dkg::group_commitments(participant).ok_or(FrostError::IncompleteKeyGeneration)?;
+        let secret_share = participant
+            .get_secret_share()
+            .ok_or(FrostError::IncompleteKeyGeneration)?;
+        let min_signers = u16::try_from(commitments.len())
+            .ok()
+            .filter(|min_signers| *min_signers >= 2)
+            .ok_or(FrostError::InvalidMinSigners)?;
+
+        Ok(KeyPackage::new(
+            Identifier::new(secret_share.identifier.0)?,
+            SigningShare(secret_share.value.0),
+            VerificationKey::from_point(commitments[0]),
+            min_signers,
+        ))
+    }
+
+    /// Returns the identifier of this participant.
+    pub fn identifier(&self) -> &Identifier {
+        &self.identifier
+    }
+
+    /// Returns the signing share of this participant.
+    pub fn signing_share(&self) -> &SigningShare {
+        &self.signing_share
+    }
+
+    /// Returns the verifying share of this participant.
+    pub fn verifying_share(&self) -> &Element {
+        &self.verifying_share
+    }
+
+    /// Returns the group verification key.
+    pub fn verifying_key(&self) -> &VerificationKey {
+        &self.verifying_key
+    }
+
+    /// Returns the number of signers needed to sign.
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+/// The public key material of the group: each participant's verifying
+/// share, and the group verification key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyPackage {
+    verifying_shares: BTreeMap<Identifier, Element>,
+    verifying_key: VerificationKey,
+}
+
+impl PublicKeyPackage {
+    /// Creates a public key package.
+    pub fn new(
+        verifying_shares: BTreeMap<Identifier, Element>,
+        verifying_key: VerificationKey,
+    ) -> PublicKeyPackage {
+        PublicKeyPackage {
+            verifying_shares,
+            verifying_key,
+        }
+    }
+
+    /// Collects the public key package from a participant that has
+    /// completed a `frost-dkg` key generation, with the verifying share of
+    /// every participant whose shares were used.
+    ///
+    /// Every participant computes the same package; see
+    /// [`KeyPackage::from_participant`].
+    #[cfg(feature = "ecc-group")]
+    pub fn from_participant<I>(
+        participant: &Participant<I, Element>,
+    ) -> Result<PublicKeyPackage, FrostError>
+    where
+        I: ParticipantImpl<Element> + Default,
+    {
+        let commitments =
+            dkg::group_commitments(participant).ok_or(FrostError::IncompleteKeyGeneration)?;
+        let verifying_shares = participant
+            .get_valid_participant_ids()
+            .values()
+            .map(|id| {
+                let identifier = Identifier::new(id.0)?;
+                Ok((identifier, dkg::verifying_share(&commitments, &id.0)))
+            })
+            .collect::<Result<_, FrostError>>()?;
+
+        Ok(PublicKeyPackage::new(
+            verifying_shares,
+            VerificationKey::from_point(commitments[0]),
+        ))
+    }
+
+    /// Returns the verifying share of each participant.
+    pub fn verifying_shares(&self) -> &BTreeMap<Identifier, Element> {
+        &self.verifying_shares
+    }
+
+    /// Returns the group verification key.
+    pub fn verifying_key(&self) -> &VerificationKey {
+        &self.verifying_key
+    }
+}
+
+/// Generates a random group signing key and splits it into `max_signers`
+/// shares, any `min_signers` of which can sign.
+///
+/// The dealer learns the group signing key, and must be trusted to discard
+/// it.
+pub fn generate_with_dealer<R: CryptoRngCore>(
+    max_signers: u16,
+    min_signers: u16,
+    rng: &mut R,
+) -> Result<(BTreeMap<Identifier, KeyPackage>, PublicKeyPackage), FrostError> {
+    let mut secret = Fr::rand(rng);
+    let mut coefficients: Vec<Fr> = (1..min_signers).map(|_| Fr::rand(rng)).collect();
+    let out = split(&secret, &coefficients, max_signers, min_signers);
+    secret.zeroize();
+    coefficients.iter_mut().for_each(Zeroize::zeroize);
+    out
+}
+
+/// Splits `secret` into `max_signers` shares, any `min_signers` of which
+/// can sign, using the polynomial with constant term `secret` and the
+/// given higher coefficients.
+///
+/// The participants have the identifiers `1` to `max_signers`. There must
+/// be `min_signers - 1` coefficients, and they must be uniformly random
+/// and kept secret for the sharing to be secure; taking them as input is
+/// mainly useful for test vectors.
+pub fn split(
+    secret: &Fr,
+    coefficients: &[Fr],
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<(BTreeMap<Identifier, KeyPackage>, PublicKeyPackage), FrostError> {
+    if min_signers < 2 {
+        return Err(FrostError::InvalidMinSigners);
+    }
+    if max_signers < min_signers {
+        return Err(FrostError::InvalidMaxSigners);
+    }
+    if coefficients.len() != usize::from(min_signers - 1) {
+        return Err(FrostError::InvalidCoefficients);
+    }
+
+    let verifying_key = VerificationKey::from_point(Element::basepoint_mul_ct(secret));
+    let mut key_packages = BTreeMap::new();
+    let mut verifying_shares = BTreeMap::new();
+    for i in 1..=max_signers {
+        let identifier = Identifier::try_from(i)?;
+        let x = identifier.to_scalar();
+
+        // Evaluate the polynomial at x with Horner's rule.
+        let share = coefficients
+            .iter()
+            .rev()
+            .fold(Fr::ZERO, |acc, coefficient| (acc + coefficient) * x)
+            + secret;
+
+        let key_package =
+            KeyPackage::new(identifier, SigningShare(share), verifying_key, min_signers);
+        verifying_shares.insert(identifier, key_package.verifying_share);
+        key_packages.insert(identifier, key_package);
+    }
+
+    Ok((
+        key_packages,
+        PublicKeyPackage::new(verifying_shares, verifying_key),
+    ))
+}
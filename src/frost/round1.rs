@@ -0,0 +1,137 @@
+//! The first round of FROST signing, in which each signer commits to a
+//! pair of nonces.
+
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+use crate::frost::{H3, SigningShare};
+use crate::{Element, Encoding, EncodingError, Fr};
+
+/// Derives a nonce from 32 random bytes and the signing share, as
+/// `H3(random_bytes || share)`, so that a weak RNG alone does not expose
+/// the share.
+fn nonce_generate(randomness: &[u8; 32], signing_share: &SigningShare) -> Fr {
+    let mut share_bytes = signing_share.to_bytes();
+    let nonce = H3(&[randomness, &share_bytes]);
+    share_bytes.zeroize();
+    nonce
+}
+
+/// The hiding and binding nonces of a signer, which must be used for at
+/// most one signature and are zeroed when dropped.
+pub struct SigningNonces {
+    pub(crate) hiding: Fr,
+    pub(crate) binding: Fr,
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    /// Generates a fresh pair of nonces.
+    pub fn new<R: CryptoRngCore>(signing_share: &SigningShare, rng: &mut R) -> SigningNonces {
+        let mut hiding_randomness = [0u8; 32];
+        let mut binding_randomness = [0u8; 32];
+        rng.fill_bytes(&mut hiding_randomness);
+        rng.fill_bytes(&mut binding_randomness);
+        SigningNonces::from_randomness(&hiding_randomness, &binding_randomness, signing_share)
+    }
+
+    /// Derives a pair of nonces from the given randomness, which must be
+    /// fresh for each signature; this is mainly useful for test vectors.
+    pub fn from_randomness(
+        hiding_randomness: &[u8; 32],
+        binding_randomness: &[u8; 32],
+        signing_share: &SigningShare,
+    ) -> SigningNonces {
+        let hiding = nonce_generate(hiding_randomness, signing_share);
+        let binding = nonce_generate(binding_randomness, signing_share);
+        SigningNonces {
+            hiding,
+            binding,
+            commitments: SigningCommitments {
+                hiding: Element::basepoint_mul_ct(&hiding),
+                binding: Element::basepoint_mul_ct(&binding),
+            },
+        }
+    }
+
+    /// Returns the commitments to these nonces.
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+}
+
+impl core::fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningNonces")
+            .field("commitments", &self.commitments)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+/// The commitments `D = d * B` and `E = e * B` to a signer's hiding and
+/// binding nonces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitments {
+    hiding: Element,
+    binding: Element,
+}
+
+impl SigningCommitments {
+    /// Creates commitments from the two commitment elements.
+    pub fn new(hiding: Element, binding: Element) -> SigningCommitments {
+        SigningCommitments { hiding, binding }
+    }
+
+    /// Returns the commitment to the hiding nonce.
+    pub fn hiding(&self) -> Element {
+        self.hiding
+    }
+
+    /// Returns the commitment to the binding nonce.
+    pub fn binding(&self) -> Element {
+        self.binding
+    }
+
+    /// Returns the encodings of the hiding and then the binding commitment.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.hiding.vartime_compress().0);
+        bytes[32..].copy_from_slice(&self.binding.vartime_compress().0);
+        bytes
+    }
+}
+
+impl TryFrom<[u8; 64]> for SigningCommitments {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 64]) -> Result<Self, Self::Error> {
+        Ok(SigningCommitments {
+            hiding: Encoding::try_from(&bytes[..32])?.vartime_decompress()?,
+            binding: Encoding::try_from(&bytes[32..])?.vartime_decompress()?,
+        })
+    }
+}
+
+impl From<SigningCommitments> for [u8; 64] {
+    fn from(commitments: SigningCommitments) -> [u8; 64] {
+        commitments.to_bytes()
+    }
+}
+
+/// Generates nonces for one signature, returning the nonces to keep and the
+/// commitments to send to the coordinator.
+pub fn commit<R: CryptoRngCore>(
+    signing_share: &SigningShare,
+    rng: &mut R,
+) -> (SigningNonces, SigningCommitments) {
+    let nonces = SigningNonces::new(signing_share, rng);
+    let commitments = nonces.commitments;
+    (nonces, commitments)
+}
@@ -0,0 +1,71 @@
+//! The second round of FROST signing, in which each signer produces a
+//! signature share.
+
+use crate::frost::{Identifier, KeyPackage, SigningPackage, round1::SigningNonces};
+use crate::interpolation::lagrange_coefficient;
+use crate::{EncodingError, Fr, FrostError};
+
+/// A signer's share `z_i` of the response of a signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare(pub(crate) Fr);
+
+impl SignatureShare {
+    /// Returns the encoding of this share.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl TryFrom<[u8; 32]> for SignatureShare {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(SignatureShare(Fr::from_bytes_checked(&bytes)?))
+    }
+}
+
+impl From<SignatureShare> for [u8; 32] {
+    fn from(share: SignatureShare) -> [u8; 32] {
+        share.to_bytes()
+    }
+}
+
+/// Computes this signer's share of the signature on the message in
+/// `signing_package`, as `z_i = d_i + e_i * rho_i + lambda_i * s_i * c`.
+///
+/// The nonces must be those whose commitments this signer sent in the first
+/// round, and must not be used again, so they are taken by value.
+pub fn sign(
+    signing_package: &SigningPackage,
+    nonces: SigningNonces,
+    key_package: &KeyPackage,
+) -> Result<SignatureShare, FrostError> {
+    if signing_package.commitments.len() < usize::from(key_package.min_signers()) {
+        return Err(FrostError::IncorrectNumberOfCommitments);
+    }
+
+    let identifier = key_package.identifier();
+    let commitments = signing_package
+        .commitments
+        .get(identifier)
+        .ok_or(FrostError::MissingCommitment)?;
+    if commitments != nonces.commitments() {
+        return Err(FrostError::IncorrectCommitment);
+    }
+
+    let context = signing_package.context(key_package.verifying_key())?;
+    let rho = context.binding_factors[identifier];
+    let lambda = lagrange_coefficient(
+        &identifier.to_scalar(),
+        signing_package
+            .commitments
+            .keys()
+            .map(Identifier::to_scalar),
+    );
+
+    Ok(SignatureShare(
+        nonces.hiding
+            + nonces.binding * rho
+            + lambda * key_package.signing_share().0 * context.challenge,
+    ))
+}
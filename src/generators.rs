@@ -0,0 +1,67 @@
+//! Deriving generators with no known discrete log relations.
+//!
+//! A generator is derived from a domain separator and a label by hashing
+//! them with Blake2b-512 into two field elements and mapping those with
+//! [`Element::hash_to_curve`]. Since the output is indistinguishable from a
+//! random element, nobody knows the discrete log of one derived generator
+//! with respect to another, or to [`Element::GENERATOR`].
+
+use blake2::{Blake2b512, Digest};
+
+use crate::{Element, Fq};
+
+/// Domain separator for all derived generators.
+const DERIVE_GENERATOR_DOMAIN: &[u8] = b"decaf377-derive-generator";
+
+fn hash_to_field(domain: &[u8], label: &[u8], counter: u32, i: u8) -> Fq {
+    let hash = Blake2b512::new()
+        .chain_update(DERIVE_GENERATOR_DOMAIN)
+        .chain_update((domain.len() as u64).to_le_bytes())
+        .chain_update(domain)
+        .chain_update((label.len() as u64).to_le_bytes())
+        .chain_update(label)
+        .chain_update(counter.to_le_bytes())
+        .chain_update([i])
+        .finalize();
+    Fq::from_le_bytes_mod_order(&hash)
+}
+
+impl Element {
+    /// Derives the generator for `label` under the domain separator
+    /// `domain`, which should be unique to the protocol using it.
+    ///
+    /// The result is never the identity. Precisely, for `i` in `0` and `1`,
+    /// the field element `r_i` is the Blake2b-512 hash of
+    ///
+    /// ```text
+    /// "decaf377-derive-generator" || len(domain) || domain || len(label) || label || counter || i
+    /// ```
+    ///
+    /// read in little-endian order and reduced modulo `q`, where the lengths
+    /// are 8-byte little-endian integers, `counter` is a 4-byte little-endian
+    /// integer and `i` is a single byte. The generator is
+    /// `Element::hash_to_curve(&r_0, &r_1)`. The counter starts at zero, and
+    /// is only incremented if the result would be the identity.
+    pub fn derive_generator(domain: &[u8], label: &[u8]) -> Element {
+        (0u32..)
+            .map(|counter| {
+                let r_1 = hash_to_field(domain, label, counter, 0);
+                let r_2 = hash_to_field(domain, label, counter, 1);
+                Element::hash_to_curve(&r_1, &r_2)
+            })
+            .find(|point| !point.is_identity())
+            .expect("a non-identity generator is found before the counter overflows")
+    }
+
+    /// Derives `n` generators under the domain separator `domain`, using
+    /// the 8-byte little-endian encodings of `0` to `n - 1` as labels.
+    ///
+    /// The generators for a smaller `n` are a prefix of those for a larger
+    /// one.
+    #[cfg(feature = "alloc")]
+    pub fn derive_generators(domain: &[u8], n: usize) -> alloc::vec::Vec<Element> {
+        (0..n as u64)
+            .map(|i| Element::derive_generator(domain, &i.to_le_bytes()))
+            .collect()
+    }
+}
@@ -0,0 +1,28 @@
+//! Lagrange interpolation of secret shares, shared by the threshold
+//! protocols.
+
+use core::borrow::Borrow;
+
+use crate::Fr;
+
+/// Computes the Lagrange coefficient of `identifier` for interpolating at
+/// zero over `identifiers`, which must be distinct and include `identifier`.
+pub(crate) fn lagrange_coefficient<I>(identifier: &Fr, identifiers: I) -> Fr
+where
+    I: IntoIterator,
+    I::Item: Borrow<Fr>,
+{
+    let mut num = Fr::ONE;
+    let mut den = Fr::ONE;
+    for other in identifiers {
+        let other = *other.borrow();
+        if other == *identifier {
+            continue;
+        }
+        num *= other;
+        den *= other - *identifier;
+    }
+    num * den
+        .inverse()
+        .expect("identifiers are distinct, so the denominator is nonzero")
+}
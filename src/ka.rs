@@ -0,0 +1,197 @@
+//! Diffie-Hellman key agreement over decaf377.
+//!
+//! A [`Secret`] is a scalar `a`, and its [`Public`] key is `a * B`, where `B`
+//! is either [`Element::GENERATOR`] or, for payment addresses, a diversified
+//! generator `B_d` (see [`Secret::diversified_public`]). Two parties whose
+//! public keys use the same base agree on the encoding of `a * b * B` as
+//! their [`SharedSecret`].
+//!
+//! Key agreement fails if the result is the identity, which happens exactly
+//! when the other party's public key is the identity or the secret is zero.
+
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{Element, Encoding, EncodingError, Fr, KeyAgreementError};
+
+/// A secret scalar, which is zeroed when dropped.
+#[derive(Clone)]
+pub struct Secret(Fr);
+
+/// A public key, which has been checked to be the encoding of an element.
+#[derive(Copy, Clone, Debug)]
+pub struct Public {
+    bytes: Encoding,
+    point: Element,
+}
+
+/// The encoding of a shared element, which is zeroed when dropped and
+/// compared in constant time.
+#[derive(Clone)]
+pub struct SharedSecret([u8; 32]);
+
+impl Secret {
+    /// Generates a new secret.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Secret(Fr::rand(rng))
+    }
+
+    /// Returns the encoding of the secret scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Returns the public key `a * B` for the standard generator `B`.
+    pub fn public(&self) -> Public {
+        Public::from_point(Element::basepoint_mul_ct(&self.0))
+    }
+
+    /// Returns the public key `a * B_d` for the diversified generator `B_d`.
+    ///
+    /// Only parties using `B_d` for their own public keys can agree on a
+    /// shared secret with this key; the sender of a payment does this by
+    /// deriving their ephemeral public key from the recipient's `B_d`.
+    pub fn diversified_public(&self, diversified_generator: &Element) -> Public {
+        Public::from_point(diversified_generator.scalar_mul_ct(&self.0))
+    }
+
+    /// Computes the shared secret with `other`.
+    ///
+    /// Returns an error if the result would be the identity.
+    pub fn key_agreement(&self, other: &Public) -> Result<SharedSecret, KeyAgreementError> {
+        let shared = other.point.scalar_mul_ct(&self.0);
+        if shared.is_identity() {
+            return Err(KeyAgreementError::IdentitySharedSecret);
+        }
+        Ok(SharedSecret(shared.compress().0))
+    }
+}
+
+impl From<Fr> for Secret {
+    fn from(scalar: Fr) -> Secret {
+        Secret(scalar)
+    }
+}
+
+impl TryFrom<[u8; 32]> for Secret {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(Secret(Fr::from_bytes_checked(&bytes)?))
+    }
+}
+
+impl TryFrom<&[u8]> for Secret {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        bytes.try_into()
+    }
+}
+
+impl core::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Public {
+    fn from_point(point: Element) -> Self {
+        Public {
+            bytes: point.vartime_compress(),
+            point,
+        }
+    }
+
+    /// Returns the encoding of this public key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.bytes.0
+    }
+}
+
+impl PartialEq for Public {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for Public {}
+
+impl TryFrom<Encoding> for Public {
+    type Error = EncodingError;
+
+    fn try_from(bytes: Encoding) -> Result<Self, Self::Error> {
+        Ok(Public {
+            point: bytes.vartime_decompress()?,
+            bytes,
+        })
+    }
+}
+
+impl TryFrom<[u8; 32]> for Public {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Encoding(bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for Public {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Encoding::try_from(bytes)?.try_into()
+    }
+}
+
+impl From<Public> for [u8; 32] {
+    fn from(public: Public) -> [u8; 32] {
+        public.to_bytes()
+    }
+}
+
+impl SharedSecret {
+    /// Returns the encoding of the shared element.
+    ///
+    /// Compare shared secrets with [`ConstantTimeEq`] or `==` rather than
+    /// comparing these bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SharedSecret {}
+
+impl core::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SharedSecret(..)")
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
@@ -4,6 +4,8 @@
 //!
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use cfg_if::cfg_if;
 
@@ -12,13 +14,55 @@ pub use fields::{fp::Fp, fq::Fq, fr::Fr};
 mod sign;
 
 mod error;
-pub use error::EncodingError;
+#[cfg(feature = "elgamal")]
+pub use error::ElGamalError;
+#[cfg(feature = "frost")]
+pub use error::FrostError;
+pub use error::{EncodingError, KeyAgreementError, MultiscalarMulError};
+
+mod basepoint_table;
+#[cfg(feature = "alloc")]
+mod batch_compress;
+#[cfg(feature = "alloc")]
+mod batch_decompress;
+mod double_base;
+pub use basepoint_table::BasepointTable;
+#[cfg(feature = "alloc")]
+mod msm;
+#[cfg(feature = "alloc")]
+pub use msm::VartimePrecomputedMultiscalarMul;
+mod window;
+
+#[cfg(feature = "commitment")]
+pub mod commitment;
+#[cfg(feature = "elgamal")]
+pub mod elgamal;
+mod elligator_inverse;
+mod generators;
+#[cfg(any(feature = "frost", all(feature = "elgamal", feature = "ecc-group")))]
+mod interpolation;
+pub mod ka;
+mod uniform;
+
+#[cfg(all(feature = "ecc-group", any(feature = "frost", feature = "elgamal")))]
+mod dkg;
+#[cfg(feature = "group")]
+mod ecc;
+#[cfg(feature = "frost")]
+pub mod frost;
+#[cfg(feature = "group")]
+pub mod hash_to_group;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "signature")]
+pub mod signature;
 
 cfg_if! {
     if #[cfg(feature = "arkworks")] {
         mod ark_curve;
 
         pub use ark_curve::{Element, Encoding, ZETA};
+        pub(crate) use ark_curve::COEFF_D;
 
         pub use ark_curve::bls12_377::Bls12_377;
 
@@ -30,5 +74,7 @@ cfg_if! {
         mod min_curve;
 
         pub use min_curve::{Element, Encoding, ZETA};
+        #[cfg(feature = "alloc")]
+        pub(crate) use min_curve::COEFF_D;
     }
 }
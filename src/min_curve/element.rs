@@ -1,8 +1,13 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::{Add, Neg};
-use subtle::{Choice, ConditionallySelectable};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::EncodingError;
-use crate::{Fq, min_curve::constants::*, min_curve::encoding::Encoding, sign::Sign};
+use zeroize::Zeroize;
+
+use crate::{Fq, Fr, min_curve::constants::*, min_curve::encoding::Encoding, sign::Sign};
 
 /// A point on an Edwards curve.
 ///
@@ -27,7 +32,7 @@ impl AffinePoint {
 }
 
 /// An element of the Decaf377 group.
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct Element {
     // These elements always satisfy the invariant that x * y = t * z.
     // Furthermore, ((x/z), (y/z)) returns the affine point associated with this element.
@@ -116,7 +121,8 @@ impl Element {
         self.x == Fq::ZERO
     }
 
-    pub fn double(self) -> Self {
+    /// Returns `self + self`.
+    pub fn double(&self) -> Self {
         // https://eprint.iacr.org/2008/522 Section 3.3
         let a = self.x.square();
         let b = self.y.square();
@@ -135,15 +141,16 @@ impl Element {
         Self::new(x3, y3, z3, t3)
     }
 
-    fn scalar_mul_both<const CT: bool>(self, le_bits: &[u64]) -> Self {
+    /// Multiplies by the scalar with little-endian limbs `le_bits`, using
+    /// binary double-and-add.
+    ///
+    /// This is variable-time, and should only be used with public scalars.
+    pub fn scalar_mul_vartime(self, le_bits: &[u64]) -> Self {
         let mut acc = Self::IDENTITY;
         let mut insert = self;
         for limb in le_bits {
             for i in 0..64 {
-                let flag = ((limb >> i) & 1) as u8;
-                if CT {
-                    acc = Self::conditional_select(&acc, &(acc + insert), Choice::from(flag))
-                } else if flag == 1 {
+                if (limb >> i) & 1 == 1 {
                     acc = acc + insert;
                 }
                 insert = insert.double();
@@ -152,32 +159,50 @@ impl Element {
         acc
     }
 
-    pub fn scalar_mul_vartime(self, le_bits: &[u64]) -> Self {
-        Self::scalar_mul_both::<false>(self, le_bits)
-    }
-
+    /// Multiplies by the scalar with little-endian limbs `le_bits`, in time
+    /// depending only on the number of limbs.
+    ///
+    /// Since the group has prime order `r`, the scalar is first reduced into
+    /// [`Fr`].
     pub fn scalar_mul(self, le_bits: &[u64]) -> Self {
-        Self::scalar_mul_both::<true>(self, le_bits)
+        let two_64 = Fr::from(u64::MAX) + Fr::ONE;
+        let scalar = le_bits
+            .iter()
+            .rev()
+            .fold(Fr::ZERO, |acc, limb| acc * two_64 + Fr::from(*limb));
+        self.scalar_mul_ct(&scalar)
     }
 
     pub fn vartime_compress_to_field(&self) -> Fq {
-        let A_MINUS_D = COEFF_A - COEFF_D;
+        // 2.
+        let (_always_square, v) = Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &self.invsqrt_input());
 
+        self.compress_with_invsqrt(&v)
+    }
+
+    /// Returns `u_1 (a - d) X^2`, whose inverse square root `v` is the one
+    /// square root the encoding needs.
+    pub(crate) fn invsqrt_input(&self) -> Fq {
         // 1.
         let u_1 = (self.x + self.t) * (self.x - self.t);
 
-        // 2.
-        let (_always_square, v) =
-            Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &(u_1 * A_MINUS_D * self.x.square()));
+        u_1 * (COEFF_A - COEFF_D) * self.x.square()
+    }
+
+    /// Finishes the encoding to the field element `s`, given the inverse
+    /// square root `v` of [`Element::invsqrt_input`], in either sign.
+    pub(crate) fn compress_with_invsqrt(&self, v: &Fq) -> Fq {
+        // 1.
+        let u_1 = (self.x + self.t) * (self.x - self.t);
 
         // 3.
-        let u_2 = (v * u_1).abs();
+        let u_2 = (*v * u_1).abs();
 
         // 4.
         let u_3 = u_2 * self.z - self.t;
 
         // 5.
-        (A_MINUS_D * v * u_3 * self.x).abs()
+        ((COEFF_A - COEFF_D) * v * u_3 * self.x).abs()
     }
 
     pub fn vartime_compress(&self) -> Encoding {
@@ -186,6 +211,90 @@ impl Element {
         Encoding(bytes)
     }
 
+    /// Encodes this element in constant time.
+    ///
+    /// This produces the same encoding as [`Element::vartime_compress`], but
+    /// does not branch on the element, so it is suitable for encoding secret
+    /// points.
+    pub fn compress(&self) -> Encoding {
+        // 1.
+        let u_1 = (self.x + self.t) * (self.x - self.t);
+
+        // 2.
+        let (_always_square, v) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &self.invsqrt_input());
+
+        // 3.
+        let u_2 = (v * u_1).ct_abs();
+
+        // 4.
+        let u_3 = u_2 * self.z - self.t;
+
+        // 5.
+        let s = ((COEFF_A - COEFF_D) * v * u_3 * self.x).ct_abs();
+
+        Encoding(s.to_bytes_le())
+    }
+
+    /// Encodes `[2]P` for every element `P` in `points`, using a single
+    /// field inversion for the whole batch and no square roots.
+    ///
+    /// For a doubled point, the inverse square root needed by the encoding
+    /// can be read off the doubling formulas, so the expensive part of
+    /// compression is replaced by a batch inversion. This is the decaf377
+    /// analogue of Ristretto's `double_and_compress_batch`.
+    ///
+    /// This is variable-time, and should only be used on public points.
+    #[cfg(feature = "alloc")]
+    pub fn double_and_compress_batch(points: &[Element]) -> Vec<Encoding> {
+        let a_minus_d = COEFF_A - COEFF_D;
+        let one_plus_d = Fq::ONE + COEFF_D;
+
+        // Doubling as in `Element::double` gives 2P = (E F : G H : F G : E H).
+        // Substituting into step 2 of the encoding, the square root of
+        // u_1 (a - d) X^2 is (1 + d) E^3 F, so only its inverse needs to be
+        // computed.
+        let states: Vec<_> = points
+            .iter()
+            .map(|point| {
+                let a = point.x.square();
+                let b = point.y.square();
+                let mut c = point.z.square();
+                c += c;
+                let mut e = point.x * point.y;
+                e += e;
+                let g = b - a;
+                let f = g - c;
+                let h = -a - b;
+                (e * f, f * g, e * h, one_plus_d * e.square() * e * f)
+            })
+            .collect();
+
+        let mut invsqrts: Vec<Fq> = states.iter().map(|state| state.3).collect();
+        Fq::batch_inverse(&mut invsqrts);
+
+        states
+            .iter()
+            .zip(invsqrts)
+            .map(|(&(x, z, t, _), v)| {
+                // 1.
+                let u_1 = (x + t) * (x - t);
+
+                // 2. v = 0 on the identity point, which gives s = 0 below.
+
+                // 3.
+                let u_2 = (v * u_1).abs();
+
+                // 4.
+                let u_3 = u_2 * z - t;
+
+                // 5.
+                let s = (a_minus_d * v * u_3 * x).abs();
+
+                Encoding(s.to_bytes_le())
+            })
+            .collect()
+    }
+
     /// Elligator 2 map to decaf377 point
     fn elligator_map(r_0: &Fq) -> Self {
         // Ref: `Decaf_1_1_Point.elligator` (optimized) in `ristretto.sage`
@@ -246,18 +355,39 @@ impl Element {
 
 impl Encoding {
     pub fn vartime_decompress(&self) -> Result<Element, EncodingError> {
+        let (s, invsqrt_input) = self.vartime_invsqrt_input()?;
+
+        // 5. sqrt
+        let (was_square, v) = Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &invsqrt_input);
+        if !was_square {
+            return Err(EncodingError::NotASquare);
+        }
+
+        Ok(Encoding::decompress_with_invsqrt(&s, v))
+    }
+
+    /// Checks this encoding and returns its field element `s` along with
+    /// `u_2 u_1^2`, whose inverse square root is the one square root the
+    /// decoding needs; the encoding is valid if and only if that is square.
+    pub(crate) fn vartime_invsqrt_input(&self) -> Result<(Fq, Fq), EncodingError> {
         // Top three bits of last byte must be zero
         if self.0[31] >> 5 != 0u8 {
-            return Err(EncodingError::InvalidEncoding);
+            return Err(EncodingError::NonZeroHighBits);
         }
 
         // 1/2. Reject unless s is canonically encoded and nonnegative.
         // Check bytes correspond to valid field element (i.e. less than field modulus)
         let s = Fq::from_bytes_checked(&self.0)?;
         if s.is_negative() {
-            return Err(EncodingError::InvalidEncoding);
+            return Err(EncodingError::NegativeS);
         }
 
+        let (u_1, u_2) = Encoding::decompress_u(&s);
+        Ok((s, u_2 * u_1.square()))
+    }
+
+    /// Computes `u_1 = 1 - s^2` and `u_2 = u_1^2 - 4d s^2`.
+    fn decompress_u(s: &Fq) -> (Fq, Fq) {
         // 3. u_1 <- 1 - s^2
         let ss = s.square();
         let u_1 = Fq::ONE - ss;
@@ -265,11 +395,14 @@ impl Encoding {
         // 4. u_2 <- u_1^2 - 4d s^2
         let u_2 = u_1.square() - (Fq::from(4u32) * COEFF_D) * ss;
 
-        // 5. sqrt
-        let (was_square, mut v) = Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &(u_2 * u_1.square()));
-        if !was_square {
-            return Err(EncodingError::InvalidEncoding);
-        }
+        (u_1, u_2)
+    }
+
+    /// Finishes decoding the element with field element `s`, given the
+    /// inverse square root `v` of the second value of
+    /// [`Encoding::vartime_invsqrt_input`], in either sign.
+    pub(crate) fn decompress_with_invsqrt(s: &Fq, mut v: Fq) -> Element {
+        let (u_1, u_2) = Encoding::decompress_u(s);
 
         // 6. sign check
         let two_s_u_1 = (Fq::ONE + Fq::ONE) * s * u_1;
@@ -278,13 +411,56 @@ impl Encoding {
             v = -v;
         }
 
+        // 7. coordinates
+        let x = two_s_u_1 * v.square() * u_2;
+        let y = (Fq::ONE + s.square()) * v * u_1;
+        let z = Fq::ONE;
+        let t = x * y;
+
+        Element::new(x, y, z, t)
+    }
+
+    /// Decodes this encoding in constant time, returning `None` if it is not
+    /// the canonical encoding of a decaf377 element.
+    ///
+    /// Unlike [`Encoding::vartime_decompress`], this does not branch on the
+    /// encoding, so it is suitable for decoding secret points.
+    pub fn decompress_ct(&self) -> CtOption<Element> {
+        // Top three bits of last byte must be zero
+        let high_bits_clear = (self.0[31] >> 5).ct_eq(&0u8);
+
+        // 1/2. Reject unless s is canonically encoded and nonnegative.
+        let s = Fq::from_raw_bytes(&self.0);
+        let s_is_canonical = s.to_bytes_le().ct_eq(&self.0);
+        let s_is_nonnegative = !s.ct_is_negative();
+
+        // 3. u_1 <- 1 - s^2
+        let ss = s.square();
+        let u_1 = Fq::ONE - ss;
+
+        // 4. u_2 <- u_1^2 - 4d s^2
+        let u_2 = u_1.square() - (Fq::from(4u32) * COEFF_D) * ss;
+
+        // 5. sqrt
+        let (was_square, v) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &(u_2 * u_1.square()));
+
+        // 6. sign check
+        let two_s_u_1 = (Fq::ONE + Fq::ONE) * s * u_1;
+        let check = two_s_u_1 * v;
+        let v = Fq::conditional_select(&v, &-v, check.ct_is_negative());
+
         // 7. coordinates
         let x = two_s_u_1 * v.square() * u_2;
         let y = (Fq::ONE + ss) * v * u_1;
         let z = Fq::ONE;
         let t = x * y;
 
-        Ok(Element::new(x, y, z, t))
+        // Skip the on-curve check in `Element::new`, since the coordinates
+        // are only meaningful when the encoding turns out to be valid.
+        CtOption::new(
+            Element { x, y, z, t },
+            high_bits_clear & s_is_canonical & s_is_nonnegative & was_square,
+        )
     }
 }
 
@@ -339,12 +515,82 @@ impl PartialEq for Element {
     }
 }
 
+impl Eq for Element {}
+
+impl ConstantTimeEq for Element {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The same check as `PartialEq`, without branching.
+        (self.x * other.y).ct_eq(&(other.x * self.y))
+    }
+}
+
+impl Default for Element {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Zeroize for Element {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+        self.t.zeroize();
+    }
+}
+
+impl core::iter::Sum<Self> for Element {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, Add::add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Element> for Element {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, |acc, point| acc + *point)
+    }
+}
+
+impl core::fmt::Debug for Element {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // This prints the hex of the encoding of self, rather than the
+        // coordinates, because that's what's most useful to downstream
+        // consumers of the library.
+        write!(f, "decaf377::Element({})", self.vartime_compress().to_hex())
+    }
+}
+
+impl core::fmt::Display for Element {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "decaf377::Element({})", self.vartime_compress().to_hex())
+    }
+}
+
+impl core::hash::Hash for Element {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Equal elements can have different projective coordinates, so hash
+        // the canonical encoding to stay consistent with `PartialEq`.
+        self.vartime_compress().0.hash(state);
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    /// Orders elements by their canonical encodings.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.vartime_compress().cmp(&other.vartime_compress())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    use crate::Fr;
-
     #[test]
     fn test_basic_equalities() {
         assert_eq!(Element::GENERATOR, Element::GENERATOR);
@@ -388,6 +634,126 @@ mod test {
         let generator = Element::GENERATOR;
         assert_eq!(generator - generator, Element::IDENTITY);
     }
+
+    #[test]
+    fn test_constant_time_encoding_matches_vartime() {
+        let mut accumulator = Element::IDENTITY;
+        for _ in 0..16 {
+            let encoding = accumulator.vartime_compress();
+            assert_eq!(accumulator.compress(), encoding);
+            assert_eq!(
+                Option::<Element>::from(encoding.decompress_ct()),
+                Some(accumulator)
+            );
+            accumulator = accumulator + Element::GENERATOR;
+        }
+
+        assert!(bool::from(Encoding([0xff; 32]).decompress_ct().is_none()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_multiscalar_mul_matches_scalar_mul() {
+        let scalars = [Fr::from(3u8), -Fr::from(11u8), Fr::from(u64::MAX)];
+        let points = [
+            Element::GENERATOR,
+            Element::GENERATOR.double(),
+            -Element::GENERATOR,
+        ];
+        let expected = points[0] * scalars[0] + points[1] * scalars[1] + points[2] * scalars[2];
+
+        assert_eq!(
+            Element::vartime_multiscalar_mul(&scalars, &points).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Element::multiscalar_mul(&scalars, &points).unwrap(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_batch_compress_matches_compress() {
+        let mut points = [Element::IDENTITY; 8];
+        for i in 1..points.len() {
+            points[i] = points[i - 1] + Element::GENERATOR;
+        }
+
+        let encodings = Element::batch_compress(&points);
+        let doubled_encodings = Element::double_and_compress_batch(&points);
+        for (i, point) in points.iter().enumerate() {
+            assert_eq!(encodings[i], point.vartime_compress());
+            assert_eq!(encodings[i], point.compress());
+            assert_eq!(doubled_encodings[i], point.double().vartime_compress());
+        }
+    }
+
+    #[test]
+    fn test_equal_elements_order_equally() {
+        let point = Element::GENERATOR * Fr::from(5u64);
+        let lambda = Fq::from(7u64);
+        let scaled = Element {
+            x: lambda * point.x,
+            y: lambda * point.y,
+            z: lambda * point.z,
+            t: lambda * point.t,
+        };
+        let torsion = Element {
+            x: -point.x,
+            y: -point.y,
+            z: point.z,
+            t: point.t,
+        };
+
+        for other in [scaled, torsion] {
+            assert_eq!(point, other);
+            assert_eq!(point.cmp(&other), core::cmp::Ordering::Equal);
+        }
+        assert!(Element::IDENTITY < Element::GENERATOR);
+    }
+
+    #[test]
+    fn test_double_scalar_mul_basepoint_matches_scalar_mul() {
+        let a = Fr::from(u64::MAX);
+        let b = -Fr::from(7u8);
+        let point = Element::GENERATOR.double();
+        assert_eq!(
+            Element::vartime_double_scalar_mul_basepoint(&a, &point, &b),
+            point * a + Element::GENERATOR * b
+        );
+    }
+
+    #[test]
+    fn test_constant_time_scalar_mul_matches_vartime() {
+        let point = Element::GENERATOR.double();
+        let scalars = [
+            Fr::ZERO,
+            Fr::ONE,
+            -Fr::ONE,
+            Fr::from(8u8),
+            -Fr::from(8u8),
+            Fr::from(u64::MAX),
+            Fr::FIELD_SIZE_POWER_OF_TWO,
+        ];
+        for scalar in scalars {
+            let limbs = scalar.to_le_limbs();
+            assert_eq!(point * scalar, point.scalar_mul_vartime(&limbs));
+            assert_eq!(point.scalar_mul(&limbs), point.scalar_mul_vartime(&limbs));
+        }
+
+        // Unreduced scalars are reduced modulo the group order.
+        let limbs = [u64::MAX; 5];
+        assert_eq!(point.scalar_mul(&limbs), point.scalar_mul_vartime(&limbs));
+    }
+
+    #[test]
+    fn test_basepoint_table_matches_scalar_mul() {
+        let table = crate::BasepointTable::create(&Element::GENERATOR);
+        for scalar in [Fr::ZERO, Fr::ONE, -Fr::ONE, Fr::from(u64::MAX)] {
+            assert_eq!(&table * &scalar, Element::GENERATOR * scalar);
+        }
+    }
 }
 
 impl From<&Element> for Encoding {
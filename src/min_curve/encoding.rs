@@ -1,4 +1,29 @@
 use crate::EncodingError;
 
-#[derive(Copy, Clone, Default, Eq, Ord, PartialOrd, PartialEq, Debug)]
+#[derive(Copy, Clone, Default, Eq, Ord, PartialOrd, PartialEq)]
 pub struct Encoding(pub [u8; 32]);
+
+impl core::fmt::Debug for Encoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "decaf377::Encoding({})", self.to_hex())
+    }
+}
+
+impl Encoding {
+    /// Returns the encoding as lowercase hex, without allocating.
+    pub(crate) fn to_hex(self) -> Hex {
+        Hex(self.0)
+    }
+}
+
+/// Formats 32 bytes as lowercase hex.
+pub(crate) struct Hex([u8; 32]);
+
+impl core::fmt::Display for Hex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
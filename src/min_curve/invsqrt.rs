@@ -57,20 +57,6 @@ impl Fq {
         z
     }
 
-    fn pow_le_limbs(&self, limbs: &[u64]) -> Self {
-        let mut acc = Self::ONE;
-        let mut insert = *self;
-        for limb in limbs {
-            for i in 0..64 {
-                if (limb >> i) & 1 == 1 {
-                    acc *= insert;
-                }
-                insert *= insert;
-            }
-        }
-        acc
-    }
-
     /// Computes the square root of a ratio of field elements, returning:
     ///
     /// - `(true, sqrt(num/den))` if `num` and `den` are both nonzero and `num/den` is square;
@@ -84,7 +70,9 @@ impl Fq {
         if den == &Fq::ZERO {
             return (false, *den);
         }
-        let x = *num / *den;
+        // Dividing by one is free, which batched callers rely on after
+        // inverting their denominators together.
+        let x = if *den == Fq::ONE { *num } else { *num / *den };
         // Because num was not zero, this will only be 1 or -1
         let symbol = x.pow_le_limbs(&Fq::MODULUS_MINUS_ONE_DIV_TWO_LIMBS);
         if symbol == Self::ONE {
@@ -4,6 +4,8 @@ pub mod encoding;
 mod invsqrt;
 mod ops;
 
+#[cfg(feature = "alloc")]
+pub(crate) use constants::COEFF_D;
 pub use constants::ZETA;
 pub use element::Element;
 pub use encoding::Encoding;
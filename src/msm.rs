@@ -0,0 +1,103 @@
+//! Multiscalar multiplication.
+//!
+//! These algorithms only use the public group operations on [`Element`], so
+//! the same code serves both the arkworks and the minimal curve backends.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use zeroize::Zeroize;
+
+use crate::{Element, Fr, MultiscalarMulError};
+
+mod pippenger;
+mod precomputed;
+mod straus;
+
+pub use precomputed::VartimePrecomputedMultiscalarMul;
+
+/// Inputs with at least this many terms use Pippenger's method; smaller ones
+/// use Straus' method, whose per-term cost is higher but which has no bucket
+/// overhead.
+const PIPPENGER_THRESHOLD: usize = 190;
+
+impl Element {
+    /// Given an iterator of (possibly secret) scalars and an iterator of
+    /// public points, compute
+    /// $$
+    /// Q = \[c\_1\] P\_1 + \cdots + \[c\_n\] P\_n,
+    /// $$
+    /// using operations whose timing does not depend on the scalars.
+    ///
+    /// The number of terms is not hidden. This is slower than
+    /// [`Element::vartime_multiscalar_mul`], which should be preferred when
+    /// the scalars are public.
+    ///
+    /// As with the variable-time version, an error is returned if the
+    /// iterators have different lengths.
+    pub fn multiscalar_mul<I, J>(scalars: I, points: J) -> Result<Element, MultiscalarMulError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fr>,
+        J: IntoIterator,
+        J::Item: Borrow<Element>,
+    {
+        let mut scalars: Vec<Fr> = scalars.into_iter().map(|s| *s.borrow()).collect();
+        let points: Vec<Element> = points.into_iter().map(|p| *p.borrow()).collect();
+
+        let result = if scalars.len() != points.len() {
+            Err(MultiscalarMulError::LengthMismatch {
+                scalars: scalars.len(),
+                points: points.len(),
+            })
+        } else {
+            Ok(straus::constant_time(&scalars, &points))
+        };
+
+        scalars.zeroize();
+
+        result
+    }
+
+    /// Given an iterator of public scalars and an iterator of public points,
+    /// compute
+    /// $$
+    /// Q = \[c\_1\] P\_1 + \cdots + \[c\_n\] P\_n,
+    /// $$
+    /// using variable-time operations.
+    ///
+    /// Small inputs are evaluated with Straus' method using width-5 NAF
+    /// digits, and larger ones with Pippenger's bucket method, with a window
+    /// size chosen from the number of terms. With the `parallel` feature,
+    /// the buckets for each window are accumulated on separate threads.
+    ///
+    /// The iterators are not required to be `ExactSizeIterator`s, since those
+    /// are not closed under chaining; instead, an error is returned if they
+    /// turn out to have different lengths.
+    pub fn vartime_multiscalar_mul<I, J>(
+        scalars: I,
+        points: J,
+    ) -> Result<Element, MultiscalarMulError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fr>,
+        J: IntoIterator,
+        J::Item: Borrow<Element>,
+    {
+        let scalars: Vec<Fr> = scalars.into_iter().map(|s| *s.borrow()).collect();
+        let points: Vec<Element> = points.into_iter().map(|p| *p.borrow()).collect();
+
+        if scalars.len() != points.len() {
+            return Err(MultiscalarMulError::LengthMismatch {
+                scalars: scalars.len(),
+                points: points.len(),
+            });
+        }
+
+        if scalars.len() < PIPPENGER_THRESHOLD {
+            Ok(straus::vartime(&scalars, &points))
+        } else {
+            Ok(pippenger::vartime(&scalars, &points))
+        }
+    }
+}
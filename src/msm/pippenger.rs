@@ -0,0 +1,70 @@
+//! Pippenger's bucket method for multiscalar multiplication.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Element, Fr};
+
+/// Picks the window width for `n` terms.
+///
+/// The bucket accumulation costs about `n` additions per window, and the
+/// bucket reduction about `2^w` additions per window, so wider windows only
+/// pay off once there are enough terms to fill the buckets.
+fn window_width(n: usize) -> usize {
+    if n < 500 {
+        6
+    } else if n < 800 {
+        7
+    } else {
+        8
+    }
+}
+
+/// Computes `sum_i scalars[i] * points[i]` in variable time.
+///
+/// Each scalar is written in signed radix `2^w`. For every digit position,
+/// the points are sorted into `2^(w-1)` buckets by the magnitude of their
+/// digit, and the buckets are then summed with the appropriate weights.
+/// The per-position sums are finally combined using Horner's rule.
+pub(super) fn vartime(scalars: &[Fr], points: &[Element]) -> Element {
+    let w = window_width(points.len());
+    let digits_count = Fr::radix_2w_digit_count(w);
+    let buckets_count = 1 << (w - 1);
+
+    let digits: Vec<[i8; 64]> = scalars.iter().map(|s| s.as_radix_2w(w)).collect();
+
+    let column_sum = |digit_index: usize| -> Element {
+        let mut buckets = vec![Element::IDENTITY; buckets_count];
+        for (digits, point) in digits.iter().zip(points) {
+            // Widen before negating, since the digit may be -2^(w-1).
+            let digit = digits[digit_index] as i16;
+            match digit.cmp(&0) {
+                Ordering::Greater => buckets[(digit - 1) as usize] += point,
+                Ordering::Less => buckets[(-digit - 1) as usize] -= point,
+                Ordering::Equal => {}
+            }
+        }
+
+        // Compute sum_j (j + 1) * buckets[j] with running sums:
+        // the bucket for j is included in the top (j + 1) partial sums.
+        let mut running_sum = Element::IDENTITY;
+        let mut column_sum = Element::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running_sum += bucket;
+            column_sum += running_sum;
+        }
+        column_sum
+    };
+
+    #[cfg(feature = "parallel")]
+    let columns: Vec<Element> = (0..digits_count).into_par_iter().map(column_sum).collect();
+    #[cfg(not(feature = "parallel"))]
+    let columns: Vec<Element> = (0..digits_count).map(column_sum).collect();
+
+    let mut columns = columns.into_iter().rev();
+    let highest = columns.next().unwrap_or(Element::IDENTITY);
+    columns.fold(highest, |acc, column| acc.mul_by_pow_2(w as u32) + column)
+}
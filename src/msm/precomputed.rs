@@ -0,0 +1,155 @@
+//! Variable-time multiscalar multiplication with precomputation for points
+//! that are reused across many computations.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+use crate::{
+    Element, Fr, MultiscalarMulError,
+    window::{NafLookupTable5, NafLookupTable8},
+};
+
+/// Precomputed tables for computing variable-time multiscalar
+/// multiplications against a fixed set of points, such as Pedersen
+/// generators.
+///
+/// For each static point this stores its odd multiples up to `127P`, so that
+/// its scalar can be recoded in width-8 NAF, which needs far fewer additions
+/// than the width-5 NAF used for points without precomputation. The tables
+/// take 8KB per point.
+#[derive(Clone)]
+pub struct VartimePrecomputedMultiscalarMul {
+    static_tables: Vec<NafLookupTable8>,
+}
+
+impl VartimePrecomputedMultiscalarMul {
+    /// Builds the precomputed tables for `static_points`.
+    pub fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Element>,
+    {
+        let static_tables = static_points
+            .into_iter()
+            .map(|point| NafLookupTable8::from(point.borrow()))
+            .collect();
+
+        Self { static_tables }
+    }
+
+    /// Returns the number of static points.
+    pub fn len(&self) -> usize {
+        self.static_tables.len()
+    }
+
+    /// Returns `true` if there are no static points.
+    pub fn is_empty(&self) -> bool {
+        self.static_tables.is_empty()
+    }
+
+    /// Given one public scalar per static point, computes
+    /// $$
+    /// Q = \[a\_1\] A\_1 + \cdots + \[a\_m\] A\_m,
+    /// $$
+    /// where the $A\_i$ are the static points.
+    pub fn vartime_multiscalar_mul<I>(
+        &self,
+        static_scalars: I,
+    ) -> Result<Element, MultiscalarMulError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fr>,
+    {
+        self.vartime_mixed_multiscalar_mul(
+            static_scalars,
+            core::iter::empty::<Fr>(),
+            core::iter::empty::<Element>(),
+        )
+    }
+
+    /// Given one public scalar per static point, along with additional
+    /// public scalars and points, computes
+    /// $$
+    /// Q = \[a\_1\] A\_1 + \cdots + \[a\_m\] A\_m + \[b\_1\] B\_1 + \cdots + \[b\_n\] B\_n,
+    /// $$
+    /// where the $A\_i$ are the static points and the $B\_j$ are the dynamic
+    /// points.
+    ///
+    /// Returns an error if the number of static scalars does not match the
+    /// number of static points, or if the dynamic scalars and points have
+    /// different lengths.
+    pub fn vartime_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Result<Element, MultiscalarMulError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fr>,
+        J: IntoIterator,
+        J::Item: Borrow<Fr>,
+        K: IntoIterator,
+        K::Item: Borrow<Element>,
+    {
+        let static_nafs: Vec<[i8; 256]> = static_scalars
+            .into_iter()
+            .map(|s| s.borrow().non_adjacent_form(8))
+            .collect();
+        let dynamic_nafs: Vec<[i8; 256]> = dynamic_scalars
+            .into_iter()
+            .map(|s| s.borrow().non_adjacent_form(5))
+            .collect();
+        let dynamic_tables: Vec<NafLookupTable5> = dynamic_points
+            .into_iter()
+            .map(|point| NafLookupTable5::from(point.borrow()))
+            .collect();
+
+        if static_nafs.len() != self.static_tables.len() {
+            return Err(MultiscalarMulError::LengthMismatch {
+                scalars: static_nafs.len(),
+                points: self.static_tables.len(),
+            });
+        }
+        if dynamic_nafs.len() != dynamic_tables.len() {
+            return Err(MultiscalarMulError::LengthMismatch {
+                scalars: dynamic_nafs.len(),
+                points: dynamic_tables.len(),
+            });
+        }
+
+        // Skip the leading zero digits shared by all of the scalars.
+        let Some(top) = static_nafs
+            .iter()
+            .chain(dynamic_nafs.iter())
+            .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+            .max()
+        else {
+            return Ok(Element::IDENTITY);
+        };
+
+        let mut acc = Element::IDENTITY;
+        for i in (0..=top).rev() {
+            acc = acc.double();
+
+            for (naf, table) in dynamic_nafs.iter().zip(dynamic_tables.iter()) {
+                match naf[i].cmp(&0) {
+                    Ordering::Greater => acc += table.select(naf[i] as usize),
+                    Ordering::Less => acc -= table.select(-naf[i] as usize),
+                    Ordering::Equal => {}
+                }
+            }
+
+            for (naf, table) in static_nafs.iter().zip(self.static_tables.iter()) {
+                match naf[i].cmp(&0) {
+                    Ordering::Greater => acc += table.select(naf[i] as usize),
+                    Ordering::Less => acc -= table.select(-naf[i] as usize),
+                    Ordering::Equal => {}
+                }
+            }
+        }
+
+        Ok(acc)
+    }
+}
@@ -0,0 +1,67 @@
+//! Straus' method for multiscalar multiplication, also known as
+//! interleaved window multiplication.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use zeroize::Zeroize;
+
+use crate::{
+    Element, Fr,
+    window::{LookupTable, NafLookupTable5},
+};
+
+/// Computes `sum_i scalars[i] * points[i]` in time independent of the
+/// scalars.
+///
+/// Each scalar is written in signed radix 16, and for every digit position
+/// the multiples `digit * P` are read out of a per-point table with a
+/// constant-time scan, so that neither the sequence of group operations nor
+/// the memory access pattern depends on the digits.
+pub(super) fn constant_time(scalars: &[Fr], points: &[Element]) -> Element {
+    let tables: Vec<LookupTable> = points.iter().map(LookupTable::from).collect();
+    let mut digits: Vec<[i8; 64]> = scalars.iter().map(|s| s.as_radix_16()).collect();
+
+    let mut acc = Element::IDENTITY;
+    for i in (0..64).rev() {
+        acc = acc.mul_by_pow_2(4);
+        for (digits, table) in digits.iter().zip(tables.iter()) {
+            acc += table.select(digits[i]);
+        }
+    }
+
+    digits.zeroize();
+
+    acc
+}
+
+/// Computes `sum_i scalars[i] * points[i]` in variable time, by sharing the
+/// doublings between all terms and adding in odd multiples of each point
+/// according to the width-5 NAF of its scalar.
+pub(super) fn vartime(scalars: &[Fr], points: &[Element]) -> Element {
+    let nafs: Vec<[i8; 256]> = scalars.iter().map(|s| s.non_adjacent_form(5)).collect();
+    let tables: Vec<NafLookupTable5> = points.iter().map(NafLookupTable5::from).collect();
+
+    // Skip the leading zero digits shared by all of the scalars.
+    let Some(top) = nafs
+        .iter()
+        .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+        .max()
+    else {
+        return Element::IDENTITY;
+    };
+
+    let mut acc = Element::IDENTITY;
+    for i in (0..=top).rev() {
+        acc = acc.double();
+        for (naf, table) in nafs.iter().zip(tables.iter()) {
+            match naf[i].cmp(&0) {
+                Ordering::Greater => acc += table.select(naf[i] as usize),
+                Ordering::Less => acc -= table.select(-naf[i] as usize),
+                Ordering::Equal => {}
+            }
+        }
+    }
+
+    acc
+}
@@ -0,0 +1,29 @@
+//! Serde support for [`Element`], shared by both curve backends.
+//!
+//! Elements are serialized as their canonical 32-byte encoding, as lowercase
+//! hex for human-readable formats and as raw bytes otherwise.
+
+use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use crate::{Element, Encoding};
+
+impl Serialize for Element {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.compress().0;
+        serdect::array::serialize_hex_lower_or_bin(&bytes, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        let _ = serdect::array::deserialize_hex_or_bin::<D>(&mut bytes, d)?;
+        Option::from(Encoding(bytes).decompress_ct()).ok_or(D::Error::custom("Invalid encoding"))
+    }
+}
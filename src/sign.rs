@@ -1,6 +1,8 @@
+use subtle::{Choice, ConditionallySelectable};
+
 use crate::Fq;
 
-pub trait Sign: core::ops::Neg<Output = Self> + Sized {
+pub trait Sign: core::ops::Neg<Output = Self> + ConditionallySelectable {
     fn is_nonnegative(&self) -> bool;
 
     fn is_negative(&self) -> bool {
@@ -10,10 +12,22 @@ pub trait Sign: core::ops::Neg<Output = Self> + Sized {
     fn abs(self) -> Self {
         if self.is_nonnegative() { self } else { -self }
     }
+
+    /// Constant-time version of [`Sign::is_negative`].
+    fn ct_is_negative(&self) -> Choice;
+
+    /// Constant-time version of [`Sign::abs`].
+    fn ct_abs(self) -> Self {
+        Self::conditional_select(&self, &-self, self.ct_is_negative())
+    }
 }
 
 impl Sign for Fq {
     fn is_nonnegative(&self) -> bool {
         (self.to_le_limbs()[0] & 1) == 0
     }
+
+    fn ct_is_negative(&self) -> Choice {
+        Choice::from((self.to_le_limbs()[0] & 1) as u8)
+    }
 }
@@ -0,0 +1,324 @@
+//! Schnorr signatures over decaf377, in the style of RedDSA.
+//!
+//! A signature on a message `M` under the verification key `A = a * B`,
+//! where `B` is [`Element::GENERATOR`], is a pair `(R, s)` with `R = r * B`
+//! for a secret nonce `r` and `s = r + c * a` for the challenge
+//! `c = H(R || A || M)`. Verification checks that `s * B - c * A = R`.
+//!
+//! `H` is Blake2b-512 of its input prefixed with a domain separator, read in
+//! little-endian order and reduced modulo the group order. Nonces are derived
+//! the same way from the signing key and the message, mixed with 32 random
+//! bytes when signing with an RNG.
+//!
+//! Keys can be randomized by a scalar `α`, as for re-randomizable spend
+//! authorization keys: if `(a, A)` is a key pair then so is
+//! `(a + α, A + α * B)`.
+//!
+//! With the `alloc` feature, many signatures can be verified at once using
+//! [`batch::Verifier`].
+
+#![allow(non_snake_case)]
+
+use core::hash::{Hash, Hasher};
+
+use ::signature::{Error, RandomizedSigner, Signer, Verifier};
+use blake2::{Blake2b512, Digest};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+use crate::{Element, Encoding, EncodingError, Fr};
+
+#[cfg(feature = "alloc")]
+pub mod batch;
+
+/// Domain separator for challenges.
+const CHALLENGE_DOMAIN: &[u8] = b"decaf377-rdsa-challenge";
+
+/// Domain separator for nonces.
+const NONCE_DOMAIN: &[u8] = b"decaf377-rdsa-nonce";
+
+/// Hashes `domain` followed by `parts` to a scalar.
+fn hash_to_scalar(domain: &[u8], parts: &[&[u8]]) -> Fr {
+    let mut hasher = Blake2b512::new();
+    hasher.update(domain);
+    for part in parts {
+        hasher.update(part);
+    }
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+/// Computes the challenge `c = H(R || A || M)`.
+pub(crate) fn challenge(R: &Encoding, A: &Encoding, msg: &[u8]) -> Fr {
+    hash_to_scalar(CHALLENGE_DOMAIN, &[&R.0, &A.0, msg])
+}
+
+/// A signature, made of the encoding of the nonce commitment `R` and the
+/// response `s`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub(crate) R: Encoding,
+    pub(crate) s: Fr,
+}
+
+impl Signature {
+    /// Returns the 64-byte encoding of this signature: `R` followed by `s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.R.0);
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+}
+
+impl From<Signature> for [u8; 64] {
+    fn from(sig: Signature) -> [u8; 64] {
+        sig.to_bytes()
+    }
+}
+
+impl TryFrom<[u8; 64]> for Signature {
+    type Error = EncodingError;
+
+    /// Parses a signature, checking that `s` is canonically encoded.
+    ///
+    /// `R` is not decompressed, as verification only compares it with the
+    /// encoding of the point it recomputes.
+    fn try_from(bytes: [u8; 64]) -> Result<Self, Self::Error> {
+        let mut R = [0u8; 32];
+        R.copy_from_slice(&bytes[..32]);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&bytes[32..]);
+        Ok(Signature {
+            R: Encoding(R),
+            s: Fr::from_bytes_checked(&s)?,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 64] = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        bytes.try_into()
+    }
+}
+
+/// A verification key `A = a * B`.
+///
+/// The key keeps its encoding alongside the point, since both are needed to
+/// verify signatures.
+#[derive(Copy, Clone, Debug)]
+pub struct VerificationKey {
+    pub(crate) bytes: Encoding,
+    pub(crate) point: Element,
+}
+
+impl VerificationKey {
+    pub(crate) fn from_point(point: Element) -> Self {
+        VerificationKey {
+            bytes: point.vartime_compress(),
+            point,
+        }
+    }
+
+    /// Returns the encoding of this key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.bytes.0
+    }
+
+    /// Randomizes this key by `randomizer`, giving the verification key of
+    /// [`SigningKey::randomize`] with the same randomizer.
+    pub fn randomize(&self, randomizer: &Fr) -> VerificationKey {
+        VerificationKey::from_point(self.point + Element::basepoint_mul_ct(randomizer))
+    }
+
+    /// Verifies `signature` on `msg` under this key.
+    ///
+    /// This runs in variable time, as all of its inputs are public.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        let c = challenge(&signature.R, &self.bytes, msg);
+        self.verify_with_challenge(&c, signature)
+    }
+
+    /// Verifies `signature` given its challenge `c`.
+    pub(crate) fn verify_with_challenge(&self, c: &Fr, signature: &Signature) -> Result<(), Error> {
+        // Comparing encodings rather than points means `R` never has to be
+        // decompressed, and rejects non-canonical encodings of it.
+        let R = Element::vartime_double_scalar_mul_basepoint(&-*c, &self.point, &signature.s);
+        if R.vartime_compress() == signature.R {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl PartialEq for VerificationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for VerificationKey {}
+
+impl Hash for VerificationKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.0.hash(state);
+    }
+}
+
+impl TryFrom<Encoding> for VerificationKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: Encoding) -> Result<Self, Self::Error> {
+        Ok(VerificationKey {
+            point: bytes.vartime_decompress()?,
+            bytes,
+        })
+    }
+}
+
+impl TryFrom<[u8; 32]> for VerificationKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Encoding(bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for VerificationKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Encoding::try_from(bytes)?.try_into()
+    }
+}
+
+impl From<VerificationKey> for [u8; 32] {
+    fn from(vk: VerificationKey) -> [u8; 32] {
+        vk.to_bytes()
+    }
+}
+
+impl From<&SigningKey> for VerificationKey {
+    fn from(sk: &SigningKey) -> VerificationKey {
+        sk.vk
+    }
+}
+
+impl Verifier<Signature> for VerificationKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        VerificationKey::verify(self, msg, signature)
+    }
+}
+
+/// A signing key `a`, stored with its verification key.
+///
+/// The secret scalar is zeroed when the key is dropped.
+#[derive(Clone)]
+pub struct SigningKey {
+    sk: Fr,
+    vk: VerificationKey,
+}
+
+impl SigningKey {
+    /// Generates a new signing key.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Fr::rand(rng).into()
+    }
+
+    /// Returns the encoding of the secret scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.sk.to_bytes()
+    }
+
+    /// Returns the verification key for this signing key.
+    pub fn verification_key(&self) -> VerificationKey {
+        self.vk
+    }
+
+    /// Randomizes this key by `randomizer`, giving the signing key `a + α`.
+    pub fn randomize(&self, randomizer: &Fr) -> SigningKey {
+        (self.sk + randomizer).into()
+    }
+
+    /// Signs `msg`, mixing randomness from `rng` into the nonce.
+    pub fn sign<R: CryptoRngCore>(&self, rng: &mut R, msg: &[u8]) -> Signature {
+        let mut randomness = [0u8; 32];
+        rng.fill_bytes(&mut randomness);
+        self.sign_with_randomness(&randomness, msg)
+    }
+
+    fn sign_with_randomness(&self, randomness: &[u8; 32], msg: &[u8]) -> Signature {
+        let mut sk_bytes = self.sk.to_bytes();
+        let mut r = hash_to_scalar(NONCE_DOMAIN, &[randomness, &sk_bytes, msg]);
+        sk_bytes.zeroize();
+
+        let R = Element::basepoint_mul_ct(&r).vartime_compress();
+        let c = challenge(&R, &self.vk.bytes, msg);
+        let s = r + c * self.sk;
+        r.zeroize();
+
+        Signature { R, s }
+    }
+}
+
+impl From<Fr> for SigningKey {
+    fn from(sk: Fr) -> SigningKey {
+        SigningKey {
+            sk,
+            vk: VerificationKey::from_point(Element::basepoint_mul_ct(&sk)),
+        }
+    }
+}
+
+impl TryFrom<[u8; 32]> for SigningKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(Fr::from_bytes_checked(&bytes)?.into())
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = EncodingError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        bytes.try_into()
+    }
+}
+
+impl core::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningKey").field("vk", &self.vk).finish()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.sk.zeroize();
+    }
+}
+
+impl Signer<Signature> for SigningKey {
+    /// Signs `msg` with a nonce derived from the key and message alone.
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        Ok(self.sign_with_randomness(&[0u8; 32], msg))
+    }
+}
+
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        Ok(self.sign(rng, msg))
+    }
+}
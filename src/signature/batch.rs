@@ -0,0 +1,143 @@
+//! Batch verification of signatures.
+//!
+//! A batch of signatures `(R_i, s_i)` on messages `M_i` under keys `A_i` is
+//! checked with a single multiscalar multiplication, by drawing a random
+//! 128-bit coefficient `z_i` for each item and checking that
+//! $$
+//! \left(\sum z\_i s\_i\right) B - \sum (z\_i c\_i) A\_i - \sum z\_i R\_i = 0.
+//! $$
+//! If any signature is invalid, this fails except with probability about
+//! `2^-128`. The equation only says that some signature is invalid, so on
+//! failure the items are verified one at a time to find which.
+
+use alloc::vec::Vec;
+
+use ::signature::Error;
+use rand_core::CryptoRngCore;
+
+use crate::signature::{Signature, VerificationKey, challenge};
+use crate::{Element, Encoding, Fr};
+
+/// A signature to be verified as part of a batch, with its challenge
+/// already computed so that the message does not need to be kept.
+#[derive(Clone, Debug)]
+pub struct Item {
+    vk: VerificationKey,
+    sig: Signature,
+    c: Fr,
+}
+
+impl<'msg, M: AsRef<[u8]> + ?Sized> From<(VerificationKey, Signature, &'msg M)> for Item {
+    fn from((vk, sig, msg): (VerificationKey, Signature, &'msg M)) -> Self {
+        let c = challenge(&sig.R, &vk.bytes, msg.as_ref());
+        Item { vk, sig, c }
+    }
+}
+
+impl Item {
+    /// Verifies this item on its own.
+    pub fn verify_single(&self) -> Result<(), Error> {
+        self.vk.verify_with_challenge(&self.c, &self.sig)
+    }
+}
+
+/// A batch of signatures to verify together.
+#[derive(Clone, Debug, Default)]
+pub struct Verifier {
+    items: Vec<Item>,
+}
+
+impl Verifier {
+    /// Constructs an empty batch.
+    pub fn new() -> Verifier {
+        Verifier::default()
+    }
+
+    /// Queues an item, e.g. a `(vk, sig, msg)` tuple, for verification.
+    pub fn queue<I: Into<Item>>(&mut self, item: I) {
+        self.items.push(item.into());
+    }
+
+    /// Returns the number of queued items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no items are queued.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verifies every queued signature, returning the index and error of
+    /// the first invalid one if the batch fails.
+    ///
+    /// The coefficients are drawn from `rng`, which must not be predictable
+    /// by whoever produced the signatures. An empty batch is valid.
+    pub fn verify<R: CryptoRngCore>(self, rng: &mut R) -> Result<(), (usize, Error)> {
+        if self.batch_equation_holds(rng) {
+            Ok(())
+        } else {
+            self.find_invalid()
+        }
+    }
+
+    fn batch_equation_holds<R: CryptoRngCore>(&self, rng: &mut R) -> bool {
+        let encodings: Vec<Encoding> = self.items.iter().map(|item| item.sig.R).collect();
+        let Ok(Rs) = Encoding::batch_vartime_decompress(&encodings) else {
+            return false;
+        };
+
+        let n = self.items.len();
+        let mut scalars = Vec::with_capacity(2 * n + 1);
+        let mut points = Vec::with_capacity(2 * n + 1);
+        let mut B_coeff = Fr::ZERO;
+
+        for (item, R) in self.items.iter().zip(Rs) {
+            let z = {
+                let mut bytes = [0u8; 16];
+                rng.fill_bytes(&mut bytes);
+                Fr::from_le_bytes_mod_order(&bytes)
+            };
+            B_coeff += z * item.sig.s;
+            scalars.push(-(z * item.c));
+            points.push(item.vk.point);
+            scalars.push(-z);
+            points.push(R);
+        }
+        scalars.push(B_coeff);
+        points.push(Element::GENERATOR);
+
+        Element::vartime_multiscalar_mul(&scalars, &points)
+            .expect("scalars and points have the same length")
+            .is_identity()
+    }
+
+    fn find_invalid(&self) -> Result<(), (usize, Error)> {
+        self.items
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, item)| item.verify_single().map_err(|e| (i, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand_core::OsRng;
+
+    use crate::signature::SigningKey;
+
+    #[test]
+    fn batch_equation_checks_signatures() {
+        let mut verifier = Verifier::new();
+        for i in 0..4u8 {
+            let sk = SigningKey::new(&mut OsRng);
+            verifier.queue((sk.verification_key(), sk.sign(&mut OsRng, &[i]), &[i]));
+        }
+        assert!(verifier.batch_equation_holds(&mut OsRng));
+
+        verifier.items[2].c += Fr::ONE;
+        assert!(!verifier.batch_equation_holds(&mut OsRng));
+    }
+}
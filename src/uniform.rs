@@ -0,0 +1,56 @@
+//! Sampling elements from uniformly random bytes.
+
+use blake2::{Blake2b512, Digest};
+use rand_core::RngCore;
+
+use crate::{Element, Fq};
+
+/// Domain separator for [`Element::from_uniform_bytes`].
+const FROM_UNIFORM_BYTES_DOMAIN: &[u8] = b"decaf377-from-uniform-bytes";
+
+impl Element {
+    /// Maps 64 uniformly random bytes to an element, by hashing each half of
+    /// the bytes to a field element.
+    ///
+    /// The halves are not reduced modulo `q` directly: since `q` is a
+    /// 253-bit prime, that would give a noticeably biased field element.
+    /// Instead, for `i` in `0` and `1`, the field element `r_i` is the
+    /// Blake2b-512 hash of
+    ///
+    /// ```text
+    /// "decaf377-from-uniform-bytes" || i || half_i
+    /// ```
+    ///
+    /// read in little-endian order and reduced modulo `q`, where `i` is a
+    /// single byte. The two field elements are passed to
+    /// [`Element::hash_to_curve`].
+    ///
+    /// Unlike Ristretto's `from_uniform_bytes`, this is not a direct
+    /// reduction of the input. For the map that is, and which can be
+    /// inverted, see [`Element::from_uniform_representative`].
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Element {
+        let r_1 = hash_to_field(0, &bytes[..32]);
+        let r_2 = hash_to_field(1, &bytes[32..]);
+        Element::hash_to_curve(&r_1, &r_2)
+    }
+
+    /// Samples a random element, by mapping 64 bytes from `rng` with
+    /// [`Element::from_uniform_bytes`].
+    ///
+    /// This is also what `Group::random` and the arkworks `UniformRand`
+    /// implementation use, and is available without either.
+    pub fn random_from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Element {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Element::from_uniform_bytes(&bytes)
+    }
+}
+
+fn hash_to_field(i: u8, half: &[u8]) -> Fq {
+    let hash = Blake2b512::new()
+        .chain_update(FROM_UNIFORM_BYTES_DOMAIN)
+        .chain_update([i])
+        .chain_update(half)
+        .finalize();
+    Fq::from_le_bytes_mod_order(&hash)
+}
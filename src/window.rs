@@ -0,0 +1,128 @@
+//! Precomputed tables of multiples of a point, used by the windowed scalar
+//! multiplication algorithms.
+
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{Element, Fr};
+
+impl Element {
+    /// Returns `2^k * self`, computed by `k` successive doublings.
+    pub(crate) fn mul_by_pow_2(&self, k: u32) -> Element {
+        let mut result = *self;
+        for _ in 0..k {
+            result = result.double();
+        }
+        result
+    }
+
+    /// Computes `scalar * self` in constant time, with a fixed-window ladder
+    /// over the signed radix-16 digits of the scalar.
+    pub(crate) fn scalar_mul_ct(&self, scalar: &Fr) -> Element {
+        let table = LookupTable::from(self);
+        let mut digits = scalar.as_radix_16();
+
+        // Horner's rule, from the most significant digit down: every step
+        // does four doublings and one addition of a table entry, whatever
+        // the digits are.
+        let mut acc = table.select(digits[63]);
+        for digit in digits[..63].iter().rev() {
+            acc = acc.mul_by_pow_2(4) + table.select(*digit);
+        }
+
+        digits.zeroize();
+
+        acc
+    }
+}
+
+/// Holds the multiples `[P, 2P, ..., 8P]` of a point `P`, for use with
+/// signed radix-16 recodings.
+#[derive(Copy, Clone)]
+pub(crate) struct LookupTable(pub(crate) [Element; 8]);
+
+impl LookupTable {
+    /// Given `-8 <= x <= 8`, returns `x * P` in constant time.
+    pub(crate) fn select(&self, x: i8) -> Element {
+        debug_assert!(x >= -8);
+        debug_assert!(x <= 8);
+
+        // Compute |x| without branching on the sign of x.
+        let xmask = (x as i16) >> 7;
+        let xabs = ((x as i16 + xmask) ^ xmask) as u16;
+
+        // Set t = |x| * P, touching every entry of the table.
+        let mut t = Element::IDENTITY;
+        for j in 1..9 {
+            let c = xabs.ct_eq(&(j as u16));
+            t.conditional_assign(&self.0[j - 1], c);
+        }
+
+        // Now t == |x| * P; negate it if x was negative.
+        let neg_mask = ((xmask & 1) as u8).into();
+        Element::conditional_select(&t, &-t, neg_mask)
+    }
+}
+
+impl From<&Element> for LookupTable {
+    fn from(point: &Element) -> Self {
+        let mut table = [*point; 8];
+        for i in 0..7 {
+            table[i + 1] = table[i] + point;
+        }
+        LookupTable(table)
+    }
+}
+
+/// Holds the odd multiples `[P, 3P, 5P, ..., 15P]` of a point `P`, for use
+/// with width-5 non-adjacent form recodings.
+#[derive(Copy, Clone)]
+pub(crate) struct NafLookupTable5(pub(crate) [Element; 8]);
+
+impl NafLookupTable5 {
+    /// Given odd `x` with `0 < x < 16`, returns `x * P`.
+    pub(crate) fn select(&self, x: usize) -> Element {
+        debug_assert_eq!(x & 1, 1);
+        debug_assert!(x < 16);
+
+        self.0[x / 2]
+    }
+}
+
+impl From<&Element> for NafLookupTable5 {
+    fn from(point: &Element) -> Self {
+        let point_2 = point.double();
+        let mut table = [*point; 8];
+        for i in 0..7 {
+            table[i + 1] = table[i] + point_2;
+        }
+        NafLookupTable5(table)
+    }
+}
+
+/// Holds the odd multiples `[P, 3P, 5P, ..., 127P]` of a point `P`, for use
+/// with width-8 non-adjacent form recodings of scalars multiplying points
+/// that are reused across many computations.
+#[derive(Copy, Clone)]
+pub(crate) struct NafLookupTable8(pub(crate) [Element; 64]);
+
+impl NafLookupTable8 {
+    /// Given odd `x` with `0 < x < 128`, returns `x * P`.
+    pub(crate) fn select(&self, x: usize) -> Element {
+        debug_assert_eq!(x & 1, 1);
+        debug_assert!(x < 128);
+
+        self.0[x / 2]
+    }
+}
+
+impl From<&Element> for NafLookupTable8 {
+    fn from(point: &Element) -> Self {
+        let point_2 = point.double();
+        let mut table = [*point; 64];
+        for i in 0..63 {
+            table[i + 1] = table[i] + point_2;
+        }
+        NafLookupTable8(table)
+    }
+}
@@ -0,0 +1,116 @@
+use proptest::prelude::*;
+
+use decaf377_plus::commitment::{Commitment, PedersenGens, VectorPedersenGens};
+use decaf377_plus::{Element, Encoding, Fr, MultiscalarMulError};
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+#[test]
+fn generators_are_distinct_and_deterministic() {
+    let gens = PedersenGens::default();
+    assert_eq!(gens.B, Element::GENERATOR);
+    assert_ne!(gens.B_blinding, Element::GENERATOR);
+    assert!(!gens.B_blinding.is_identity());
+    assert_eq!(PedersenGens::default(), gens);
+
+    let vector_gens = VectorPedersenGens::new(8);
+    assert_eq!(vector_gens.G.len(), 8);
+    assert_eq!(vector_gens.H, gens.B_blinding);
+    assert_eq!(VectorPedersenGens::new(3).G, vector_gens.G[..3]);
+    for (i, g) in vector_gens.G.iter().enumerate() {
+        assert!(!g.is_identity());
+        assert_ne!(*g, gens.B);
+        assert_ne!(*g, gens.B_blinding);
+        assert!(!vector_gens.G[..i].contains(g));
+    }
+}
+
+#[test]
+fn commitments_open_only_to_their_values() {
+    let gens = PedersenGens::default();
+    let (value, blinding) = (Fr::from(1000u64), Fr::from(0x1234_5678u64));
+    let commitment = gens.commit(value, blinding);
+    assert!(gens.open(&commitment, value, blinding));
+    assert!(!gens.open(&commitment, value + Fr::ONE, blinding));
+    assert!(!gens.open(&commitment, value, blinding + Fr::ONE));
+}
+
+#[test]
+fn vector_commitments_check_lengths() {
+    let gens = VectorPedersenGens::new(4);
+    let values = [1u8, 2, 3, 4].map(Fr::from);
+    let blinding = Fr::from(99u8);
+
+    let commitment = gens.commit(&values, blinding).unwrap();
+    assert_eq!(gens.open(&commitment, &values, blinding), Ok(true));
+    assert_eq!(
+        gens.open(
+            &commitment,
+            &[values[1], values[0], values[2], values[3]],
+            blinding
+        ),
+        Ok(false)
+    );
+
+    let expected = MultiscalarMulError::LengthMismatch {
+        scalars: 4,
+        points: 5,
+    };
+    assert_eq!(gens.commit(&values[..3], blinding), Err(expected));
+    assert_eq!(
+        gens.open(&commitment, &values[..3], blinding),
+        Err(expected)
+    );
+}
+
+#[test]
+fn commitments_round_trip_through_encoding() {
+    let commitment = PedersenGens::default().commit(Fr::from(5u8), Fr::from(7u8));
+    let encoding = Encoding::from(commitment);
+    assert_eq!(encoding, commitment.to_encoding());
+    assert_eq!(Commitment::try_from(encoding), Ok(commitment));
+    assert_eq!(Commitment::try_from(encoding.0), Ok(commitment));
+    assert_eq!(Commitment::try_from(&encoding.0[..]), Ok(commitment));
+    assert!(Commitment::try_from([0xff; 32]).is_err());
+}
+
+proptest! {
+    #[test]
+    fn commitments_are_homomorphic(
+        v_1 in fr_strategy(),
+        r_1 in fr_strategy(),
+        v_2 in fr_strategy(),
+        r_2 in fr_strategy(),
+    ) {
+        let gens = PedersenGens::default();
+        let c_1 = gens.commit(v_1, r_1);
+        let c_2 = gens.commit(v_2, r_2);
+        prop_assert_eq!(c_1.0, gens.B * v_1 + gens.B_blinding * r_1);
+
+        prop_assert_eq!(c_1 + c_2, gens.commit(v_1 + v_2, r_1 + r_2));
+        prop_assert_eq!(c_1 - c_2, gens.commit(v_1 - v_2, r_1 - r_2));
+        prop_assert_eq!(-c_1, gens.commit(-v_1, -r_1));
+
+        let mut sum = c_1;
+        sum += c_2;
+        sum -= c_1;
+        prop_assert_eq!(sum, c_2);
+    }
+
+    #[test]
+    fn vector_commitments_are_homomorphic(
+        a in prop::collection::vec(fr_strategy(), 3),
+        b in prop::collection::vec(fr_strategy(), 3),
+        r_a in fr_strategy(),
+        r_b in fr_strategy(),
+    ) {
+        let gens = VectorPedersenGens::new(3);
+        let sum: Vec<Fr> = a.iter().zip(&b).map(|(x, y)| *x + *y).collect();
+        let c = gens.commit(&a, r_a).unwrap() + gens.commit(&b, r_b).unwrap();
+        prop_assert_eq!(gens.open(&c, &sum, r_a + r_b), Ok(true));
+    }
+}
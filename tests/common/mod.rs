@@ -0,0 +1,46 @@
+//! A frost-dkg key generation, shared by the tests of the protocols that
+//! use its key shares.
+
+use std::num::NonZeroUsize;
+
+use frost_dkg::vsss_rs::IdentifierPrimeField;
+use frost_dkg::{Parameters, SecretParticipant};
+
+use decaf377_plus::{Element, Fr};
+
+pub const THRESHOLD: usize = 3;
+pub const LIMIT: usize = 5;
+
+/// Returns `LIMIT` participants with identifiers `1` to `LIMIT`, with a
+/// threshold of `THRESHOLD`, before any round has run.
+pub fn new_participants() -> Vec<SecretParticipant<Element>> {
+    let parameters = Parameters::<Element>::new(
+        NonZeroUsize::new(THRESHOLD).unwrap(),
+        NonZeroUsize::new(LIMIT).unwrap(),
+        None,
+        None,
+    );
+    (1..=LIMIT)
+        .map(|id| {
+            let id = IdentifierPrimeField(Fr::from(id as u64));
+            SecretParticipant::<Element>::new_secret(id, &parameters).unwrap()
+        })
+        .collect()
+}
+
+/// Runs all three rounds of a key generation among [`new_participants`],
+/// returning the participants once it has completed.
+pub fn run_dkg_rounds() -> Vec<SecretParticipant<Element>> {
+    let mut participants = new_participants();
+    for _ in 0..3 {
+        let outputs: Vec<_> = participants.iter_mut().map(|p| p.run().unwrap()).collect();
+        for output in outputs {
+            for message in output.iter() {
+                participants[message.dst_ordinal]
+                    .receive(&message.data)
+                    .unwrap();
+            }
+        }
+    }
+    participants
+}
@@ -0,0 +1,183 @@
+//! Tests of the public `Element` API, which should pass against whichever
+//! curve backend is compiled in.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use proptest::prelude::*;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+use decaf377_plus::{Element, Encoding, Fq, Fr};
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+fn element_strategy() -> BoxedStrategy<Element> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes[..])))
+        .boxed()
+}
+
+#[test]
+fn default_is_identity() {
+    assert_eq!(Element::default(), Element::IDENTITY);
+    assert!(Element::default().is_identity());
+    assert_eq!(Element::default().vartime_compress(), Encoding([0u8; 32]));
+}
+
+#[test]
+fn sum_matches_repeated_addition() {
+    let points = [
+        Element::GENERATOR,
+        Element::GENERATOR.double(),
+        -Element::GENERATOR,
+    ];
+    let expected = points[0] + points[1] + points[2];
+    assert_eq!(points.iter().sum::<Element>(), expected);
+    assert_eq!(points.into_iter().sum::<Element>(), expected);
+    assert_eq!(
+        core::iter::empty::<Element>().sum::<Element>(),
+        Element::IDENTITY
+    );
+}
+
+#[test]
+fn zeroize_clears_element() {
+    let mut point = Element::GENERATOR;
+    point.zeroize();
+    assert!(point.is_identity());
+}
+
+#[test]
+fn display_and_debug_print_encoding() {
+    let point = Element::GENERATOR.double();
+    let mut hex = String::new();
+    for byte in point.vartime_compress().0 {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+
+    let expected = format!("decaf377::Element({hex})");
+    assert_eq!(format!("{point}"), expected);
+    assert_eq!(format!("{point:?}"), expected);
+    assert_eq!(
+        format!("{:?}", point.vartime_compress()),
+        format!("decaf377::Encoding({hex})")
+    );
+}
+
+#[test]
+fn equal_elements_hash_equally() {
+    let a = Element::GENERATOR + Element::GENERATOR;
+    let b = Element::GENERATOR.double();
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(set.contains(&b));
+    set.insert(b);
+    assert_eq!(set.len(), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vartime_multiscalar_mul_matches_scalar_mul() {
+    let scalars = [Fr::from(3u8), -Fr::from(11u8), Fr::from(u64::MAX)];
+    let points = [
+        Element::GENERATOR,
+        Element::GENERATOR.double(),
+        -Element::GENERATOR,
+    ];
+    let expected = points[0] * scalars[0] + points[1] * scalars[1] + points[2] * scalars[2];
+
+    assert_eq!(
+        Element::vartime_multiscalar_mul(&scalars, &points).unwrap(),
+        expected
+    );
+    assert_eq!(
+        Element::multiscalar_mul(&scalars, &points).unwrap(),
+        expected
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let point = Element::GENERATOR.double();
+
+    let json = serde_json::to_string(&point).unwrap();
+    let mut hex = String::new();
+    for byte in point.vartime_compress().0 {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+    assert_eq!(json, format!("\"{hex}\""));
+    assert_eq!(serde_json::from_str::<Element>(&json).unwrap(), point);
+
+    let bare = serde_bare::to_vec(&point).unwrap();
+    assert_eq!(serde_bare::from_slice::<Element>(&bare).unwrap(), point);
+
+    assert!(serde_json::from_str::<Element>(&format!("\"{}\"", "ff".repeat(32))).is_err());
+}
+
+#[cfg(feature = "group")]
+#[test]
+fn group_traits_match_inherent_methods() {
+    use elliptic_curve::{Group, group::GroupEncoding};
+
+    assert_eq!(<Element as Group>::identity(), Element::IDENTITY);
+    assert_eq!(<Element as Group>::generator(), Element::GENERATOR);
+    assert!(bool::from(Group::is_identity(&Element::IDENTITY)));
+    assert!(!bool::from(Group::is_identity(&Element::GENERATOR)));
+    assert_eq!(
+        Group::double(&Element::GENERATOR),
+        Element::GENERATOR + Element::GENERATOR
+    );
+
+    let point = <Element as Group>::random(rand_core::OsRng);
+    let bytes = point.to_bytes();
+    assert_eq!(bytes, point.vartime_compress().0);
+    assert_eq!(
+        Option::<Element>::from(Element::from_bytes(&bytes)),
+        Some(point)
+    );
+    assert!(bool::from(Element::from_bytes(&[0xff; 32]).is_none()));
+}
+
+#[cfg(feature = "group")]
+#[test]
+fn scalar_field_traits_match_inherent_methods() {
+    use elliptic_curve::{Field, PrimeField};
+
+    let x = Fr::from(12345u64);
+    assert_eq!(Field::square(&x), x * x);
+    assert_eq!(Field::double(&x), x + x);
+    assert_eq!(Option::<Fr>::from(Field::invert(&x)).unwrap() * x, Fr::ONE);
+    assert!(bool::from(Field::invert(&Fr::ZERO).is_none()));
+    assert_eq!(Option::<Fr>::from(Fr::from_repr(x.to_repr())), Some(x));
+}
+
+proptest! {
+    #[test]
+    fn ct_eq_matches_eq(a in element_strategy(), b in element_strategy()) {
+        prop_assert!(bool::from(a.ct_eq(&a)));
+        prop_assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+        prop_assert!(bool::from((a + b).ct_eq(&(b + a))));
+    }
+
+    #[test]
+    fn scalar_mul_is_linear(a in fr_strategy(), b in fr_strategy(), point in element_strategy()) {
+        prop_assert_eq!(point * (a + b), point * a + point * b);
+        prop_assert_eq!(point * (a * b), (point * a) * b);
+        prop_assert_eq!(point * -a, -(point * a));
+    }
+
+    #[test]
+    fn compression_round_trips(point in element_strategy()) {
+        let encoding = point.vartime_compress();
+        prop_assert_eq!(point.compress(), encoding);
+        prop_assert_eq!(encoding.vartime_decompress(), Ok(point));
+        prop_assert_eq!(Option::<Element>::from(encoding.decompress_ct()), Some(point));
+    }
+}
@@ -0,0 +1,209 @@
+use proptest::prelude::*;
+use rand_core::OsRng;
+
+use decaf377_plus::elgamal::{Ciphertext, DiscreteLogTable, ElGamalKeypair, PublicKey};
+use decaf377_plus::{ElGamalError, Element, EncodingError, Fr};
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+#[test]
+fn encryption_round_trips() {
+    let keypair = ElGamalKeypair::new(&mut OsRng);
+    let table = DiscreteLogTable::new(1000);
+
+    for m in [0u64, 1, 2, 31, 32, 999, 1000] {
+        let ciphertext = keypair.public_key().encrypt(Fr::from(m), &mut OsRng);
+        assert_eq!(
+            keypair.decrypt_to_element(&ciphertext),
+            Element::GENERATOR * Fr::from(m)
+        );
+        assert_eq!(keypair.decrypt(&ciphertext, &table), Ok(m));
+    }
+
+    let other = ElGamalKeypair::new(&mut OsRng);
+    let ciphertext = keypair.public_key().encrypt(Fr::from(7u8), &mut OsRng);
+    assert_ne!(
+        other.decrypt_to_element(&ciphertext),
+        Element::GENERATOR * Fr::from(7u8)
+    );
+}
+
+#[test]
+fn out_of_range_messages_are_rejected() {
+    let keypair = ElGamalKeypair::new(&mut OsRng);
+    let table = DiscreteLogTable::new(100);
+    for m in [101u64, 1 << 20] {
+        let ciphertext = keypair.public_key().encrypt(Fr::from(m), &mut OsRng);
+        assert_eq!(
+            keypair.decrypt(&ciphertext, &table),
+            Err(ElGamalError::MessageOutOfRange)
+        );
+    }
+    let negative = keypair.public_key().encrypt(-Fr::ONE, &mut OsRng);
+    assert_eq!(
+        keypair.decrypt(&negative, &table),
+        Err(ElGamalError::MessageOutOfRange)
+    );
+}
+
+#[test]
+fn discrete_log_table_sizes() {
+    assert_eq!(DiscreteLogTable::new(0).table_size(), 1);
+    assert_eq!(DiscreteLogTable::new(99).table_size(), 10);
+    assert_eq!(DiscreteLogTable::new(100).table_size(), 11);
+    assert_eq!(
+        DiscreteLogTable::new(u64::MAX).table_size(),
+        DiscreteLogTable::MAX_DEFAULT_TABLE_SIZE
+    );
+
+    // Every table size finds exactly the messages up to its maximum.
+    for table_size in [1, 3, 7, 16, 40] {
+        let table = DiscreteLogTable::with_table_size(30, table_size);
+        assert_eq!(table.max(), 30);
+        assert_eq!(table.table_size(), table_size);
+        for m in 0..=35u64 {
+            let expected = (m <= 30).then_some(m);
+            assert_eq!(
+                table.vartime_solve(&(Element::GENERATOR * Fr::from(m))),
+                expected,
+                "m = {m}, table_size = {table_size}"
+            );
+        }
+    }
+}
+
+#[test]
+fn discrete_log_table_handles_the_largest_range() {
+    let table = DiscreteLogTable::with_table_size(u64::MAX, 16);
+    for m in [0u64, 15, 16, 100] {
+        assert_eq!(
+            table.vartime_solve(&(Element::GENERATOR * Fr::from(m))),
+            Some(m)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn empty_discrete_log_table_panics() {
+    DiscreteLogTable::with_table_size(10, 0);
+}
+
+#[test]
+fn tallies_sum_encrypted_votes() {
+    let keypair = ElGamalKeypair::new(&mut OsRng);
+    let public_key = keypair.public_key();
+    let votes = [1u64, 0, 1, 1, 0, 1, 1];
+
+    let tally: Ciphertext = votes
+        .iter()
+        .map(|vote| public_key.encrypt(Fr::from(*vote), &mut OsRng))
+        .sum();
+    let table = DiscreteLogTable::new(votes.len() as u64);
+    assert_eq!(keypair.decrypt(&tally, &table), Ok(5));
+    assert_eq!(keypair.decrypt(&Ciphertext::ZERO, &table), Ok(0));
+}
+
+#[test]
+fn rerandomization_preserves_the_message() {
+    let keypair = ElGamalKeypair::new(&mut OsRng);
+    let public_key = keypair.public_key();
+    let ciphertext = public_key.encrypt(Fr::from(42u8), &mut OsRng);
+
+    let rerandomized = public_key.rerandomize(&ciphertext, &mut OsRng);
+    assert_ne!(rerandomized.c1, ciphertext.c1);
+    assert_ne!(rerandomized.c2, ciphertext.c2);
+    assert_eq!(
+        keypair.decrypt(&rerandomized, &DiscreteLogTable::new(100)),
+        Ok(42)
+    );
+}
+
+#[test]
+fn encryption_with_randomness_is_deterministic() {
+    let keypair = ElGamalKeypair::from(Fr::from(1234u64));
+    let public_key = keypair.public_key();
+    let (m, r) = (Fr::from(5u8), Fr::from(77u8));
+
+    let ciphertext = public_key.encrypt_with_randomness(m, &r);
+    assert_eq!(ciphertext, public_key.encrypt_with_randomness(m, &r));
+    assert_eq!(ciphertext.c1, Element::GENERATOR * r);
+    assert_eq!(
+        ciphertext.c2,
+        Element::GENERATOR * (m + r * Fr::from(1234u64))
+    );
+}
+
+#[test]
+fn keys_and_ciphertexts_round_trip_through_encoding() {
+    let keypair = ElGamalKeypair::new(&mut OsRng);
+    let restored = ElGamalKeypair::try_from(keypair.to_bytes()).unwrap();
+    assert_eq!(restored.public_key(), keypair.public_key());
+    assert!(!format!("{keypair:?}").contains(&hex::encode(keypair.to_bytes())));
+
+    let public_key = keypair.public_key();
+    let bytes: [u8; 32] = (*public_key).into();
+    assert_eq!(PublicKey::try_from(bytes), Ok(*public_key));
+    assert_eq!(PublicKey::try_from(&bytes[..]), Ok(*public_key));
+    assert_eq!(
+        public_key.to_element(),
+        Element::GENERATOR * Fr::from_bytes_checked(&keypair.to_bytes()).unwrap()
+    );
+
+    let ciphertext = public_key.encrypt(Fr::from(3u8), &mut OsRng);
+    let bytes = ciphertext.to_bytes();
+    assert_eq!(Ciphertext::try_from(bytes), Ok(ciphertext));
+    assert_eq!(Ciphertext::try_from(&bytes[..]), Ok(ciphertext));
+    assert_eq!(
+        Ciphertext::try_from(&bytes[..63]),
+        Err(EncodingError::InvalidSliceLength)
+    );
+    let mut invalid = bytes;
+    invalid[63] = 0xff;
+    assert_eq!(
+        Ciphertext::try_from(invalid),
+        Err(EncodingError::NonZeroHighBits)
+    );
+}
+
+proptest! {
+    #[test]
+    fn ciphertexts_are_homomorphic(
+        sk in fr_strategy(),
+        m_1 in fr_strategy(),
+        r_1 in fr_strategy(),
+        m_2 in fr_strategy(),
+        r_2 in fr_strategy(),
+        k in fr_strategy(),
+    ) {
+        let keypair = ElGamalKeypair::from(sk);
+        let public_key = keypair.public_key();
+        let c_1 = public_key.encrypt_with_randomness(m_1, &r_1);
+        let c_2 = public_key.encrypt_with_randomness(m_2, &r_2);
+        prop_assert_eq!(public_key.to_element(), Element::GENERATOR * sk);
+        prop_assert_eq!(c_1.c1, Element::GENERATOR * r_1);
+        prop_assert_eq!(c_1.c2, Element::GENERATOR * m_1 + Element::GENERATOR * sk * r_1);
+        prop_assert_eq!(keypair.decrypt_to_element(&c_1), Element::GENERATOR * m_1);
+
+        prop_assert_eq!(c_1 + c_2, public_key.encrypt_with_randomness(m_1 + m_2, &(r_1 + r_2)));
+        prop_assert_eq!(c_1 - c_2, public_key.encrypt_with_randomness(m_1 - m_2, &(r_1 - r_2)));
+        prop_assert_eq!(-c_1, public_key.encrypt_with_randomness(-m_1, &-r_1));
+        prop_assert_eq!(c_1 * k, public_key.encrypt_with_randomness(m_1 * k, &(r_1 * k)));
+        prop_assert_eq!(k * c_1, c_1 * k);
+
+        let mut c = c_1;
+        c += c_2;
+        c -= c_1;
+        prop_assert_eq!(c, c_2);
+        c *= k;
+        prop_assert_eq!(c, c_2 * k);
+        prop_assert_eq!(
+            keypair.decrypt_to_element(&(c_1 + c_2)),
+            Element::GENERATOR * (m_1 + m_2)
+        );
+    }
+}
@@ -2,13 +2,13 @@ use core::convert::TryFrom;
 
 use proptest::prelude::*;
 
-use decaf377::{Element, Encoding, Fq, Fr};
+use decaf377_plus::{Element, Encoding, Fq, Fr};
 
 /*
 #[test]
 fn print_fp_for_fiat_codegen() {
     use ark_ff::PrimeField;
-    use decaf377::Bls12_377;
+    use decaf377_plus::Bls12_377;
 
     let p = <Bls12_377 as ark_ec::pairing::Pairing>::BaseField::MODULUS;
     println!("p = {}", p);
@@ -94,7 +94,250 @@ fn test_encoding_matches_sage_encoding() {
     }
 }
 
+#[test]
+fn constant_time_encoding_matches_vartime_encoding() {
+    let mut accumulator = Element::default();
+    for _ in 0..16 {
+        let encoding = accumulator.vartime_compress();
+        assert_eq!(accumulator.compress(), encoding);
+        assert_eq!(
+            Option::<Element>::from(encoding.decompress_ct()),
+            Some(accumulator)
+        );
+
+        accumulator += Element::GENERATOR;
+    }
+}
+
+#[test]
+fn decompress_ct_rejects_invalid_encodings() {
+    // Negative s.
+    let mut bytes = Element::GENERATOR.vartime_compress().0;
+    bytes[0] = 9;
+    assert!(bool::from(Encoding(bytes).decompress_ct().is_none()));
+
+    // Non-canonical s.
+    assert!(bool::from(Encoding([0xff; 32]).decompress_ct().is_none()));
+
+    // High bits set.
+    let mut bytes = [0u8; 32];
+    bytes[31] = 0b1000_0000;
+    assert!(bool::from(Encoding(bytes).decompress_ct().is_none()));
+}
+
+#[test]
+fn vartime_decompress_reports_rejection_step() {
+    use decaf377_plus::EncodingError;
+
+    let mut bytes = [0u8; 32];
+    bytes[31] = 0b1000_0000;
+    assert_eq!(
+        Encoding(bytes).vartime_decompress(),
+        Err(EncodingError::NonZeroHighBits)
+    );
+
+    // The modulus starts with 0x12 in its top byte.
+    let mut bytes = [0xff; 32];
+    bytes[31] = 0x1f;
+    assert_eq!(
+        Encoding(bytes).vartime_decompress(),
+        Err(EncodingError::NonCanonicalFieldElement)
+    );
+
+    let mut bytes = Element::GENERATOR.vartime_compress().0;
+    bytes[0] = 9;
+    assert_eq!(
+        Encoding(bytes).vartime_decompress(),
+        Err(EncodingError::NegativeS)
+    );
+
+    let mut bytes = [0u8; 32];
+    bytes[0] = 2;
+    assert_eq!(
+        Encoding(bytes).vartime_decompress(),
+        Err(EncodingError::NotASquare)
+    );
+
+    assert_eq!(
+        Element::try_from(&[0u8; 31][..]),
+        Err(EncodingError::InvalidSliceLength)
+    );
+    assert_eq!(
+        Fq::from_bytes_checked(&[0xff; 32]),
+        Err(EncodingError::NonCanonicalFieldElement)
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn invalid_encoding_is_kept_for_compatibility() {
+    use decaf377_plus::EncodingError;
+
+    assert_eq!(
+        EncodingError::InvalidEncoding.to_string(),
+        "Invalid Decaf377 encoding"
+    );
+    assert_ne!(
+        Encoding([0xff; 32]).vartime_decompress(),
+        Err(EncodingError::InvalidEncoding)
+    );
+}
+
+#[cfg(feature = "group")]
+#[test]
+fn group_encoding_uses_canonical_encoding() {
+    use elliptic_curve::group::GroupEncoding;
+
+    let point = Element::GENERATOR + Element::GENERATOR;
+    let bytes = point.to_bytes();
+    assert_eq!(bytes, point.vartime_compress().0);
+    assert_eq!(
+        Option::<Element>::from(Element::from_bytes(&bytes)),
+        Some(point)
+    );
+    assert_eq!(
+        Option::<Element>::from(Element::from_bytes_unchecked(&bytes)),
+        Some(point)
+    );
+    assert!(bool::from(Element::from_bytes(&[0xff; 32]).is_none()));
+}
+
+#[test]
+fn batch_compress_handles_identity_and_empty_batches() {
+    let points = [Element::IDENTITY, Element::GENERATOR, Element::IDENTITY];
+    assert_eq!(
+        Element::batch_compress(&points),
+        points
+            .iter()
+            .map(Element::vartime_compress)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Element::double_and_compress_batch(&points),
+        points
+            .iter()
+            .map(|point| (point + point).vartime_compress())
+            .collect::<Vec<_>>()
+    );
+
+    assert!(Element::batch_compress(&[]).is_empty());
+    assert!(Element::double_and_compress_batch(&[]).is_empty());
+}
+
+#[test]
+fn batch_vartime_decompress_reports_first_invalid_index() {
+    let mut encodings: Vec<Encoding> = [
+        Element::IDENTITY,
+        Element::GENERATOR,
+        Element::GENERATOR + Element::GENERATOR,
+    ]
+    .iter()
+    .map(Element::vartime_compress)
+    .collect();
+
+    let elements = Encoding::batch_vartime_decompress(&encodings).unwrap();
+    assert_eq!(
+        elements,
+        vec![
+            Element::IDENTITY,
+            Element::GENERATOR,
+            Element::GENERATOR + Element::GENERATOR
+        ]
+    );
+
+    encodings.push(Encoding([0xff; 32]));
+    encodings[1].0[0] = 9;
+    assert_eq!(
+        Encoding::batch_vartime_decompress(&encodings),
+        Err((1, decaf377_plus::EncodingError::NegativeS))
+    );
+
+    assert_eq!(Encoding::batch_vartime_decompress(&[]), Ok(vec![]));
+
+    // s = -1 gives u_1 = 0, whose inverse square root does not exist.
+    let encodings = [
+        Element::GENERATOR.vartime_compress(),
+        Encoding((-Fq::ONE).to_bytes_le()),
+    ];
+    let error = encodings[1].vartime_decompress().unwrap_err();
+    assert_eq!(
+        Encoding::batch_vartime_decompress(&encodings),
+        Err((1, error))
+    );
+}
+
 proptest! {
+    #[test]
+    fn batch_vartime_decompress_matches_vartime_decompress(
+        bytes in prop::collection::vec(any::<[u8; 32]>(), 0..16)
+    ) {
+        let encodings: Vec<Encoding> = bytes.into_iter().map(Encoding).collect();
+        let expected = encodings
+            .iter()
+            .enumerate()
+            .map(|(i, encoding)| encoding.vartime_decompress().map_err(|e| (i, e)))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Encoding::batch_vartime_decompress(&encodings), expected);
+    }
+
+    #[test]
+    fn batch_vartime_decompress_matches_on_valid_batches(
+        bytes in prop::collection::vec(any::<[u8; 32]>(), 1..16),
+        corrupt in any::<prop::sample::Index>(),
+        byte in any::<u8>(),
+    ) {
+        let points: Vec<Element> = bytes
+            .iter()
+            .map(|bytes| Element::encode_to_curve(&Fq::from_le_bytes_mod_order(bytes)))
+            .collect();
+        let mut encodings = Element::batch_compress(&points);
+        prop_assert_eq!(Encoding::batch_vartime_decompress(&encodings), Ok(points));
+
+        // Corrupting one encoding reports it, whatever the failure.
+        let i = corrupt.index(encodings.len());
+        encodings[i].0[0] ^= byte | 1;
+        let expected = encodings
+            .iter()
+            .enumerate()
+            .map(|(i, encoding)| encoding.vartime_decompress().map_err(|e| (i, e)))
+            .collect::<Result<Vec<_>, _>>();
+        prop_assert_eq!(Encoding::batch_vartime_decompress(&encodings), expected);
+    }
+
+    #[test]
+    fn batch_compress_matches_compress(
+        bytes in prop::collection::vec(any::<[u8; 32]>(), 0..16)
+    ) {
+        // Sums of points have Z != 1, so the batch sees general
+        // projective representatives.
+        let points: Vec<Element> = bytes
+            .iter()
+            .map(|bytes| Element::encode_to_curve(&Fq::from_le_bytes_mod_order(bytes)))
+            .map(|point| point + Element::GENERATOR)
+            .collect();
+
+        let encodings = Element::batch_compress(&points);
+        let doubled_encodings = Element::double_and_compress_batch(&points);
+        for (i, point) in points.iter().enumerate() {
+            assert_eq!(encodings[i], point.vartime_compress());
+            assert_eq!(encodings[i], point.compress());
+            assert_eq!(doubled_encodings[i], (point + point).vartime_compress());
+        }
+    }
+
+    #[test]
+    fn decompress_ct_matches_vartime_decompress(bytes: [u8; 32]) {
+        let encoding = Encoding(bytes);
+        let element: Option<Element> = encoding.decompress_ct().into();
+        assert_eq!(element, encoding.vartime_decompress().ok());
+    }
+
+    #[test]
+    fn compress_matches_vartime_compress(bytes: [u8; 32]) {
+        let element = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+        assert_eq!(element.compress(), element.vartime_compress());
+    }
+
     #[test]
     fn group_encoding_round_trip_if_successful(bytes: [u8; 32]) {
         let bytes = Encoding(bytes);
@@ -121,3 +364,158 @@ proptest! {
         }
     }
 }
+
+proptest! {
+    #[test]
+    fn from_uniform_bytes_hashes_both_halves(low: [u8; 32], high: [u8; 32]) {
+        use blake2::{Blake2b512, Digest};
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&low);
+        bytes[32..].copy_from_slice(&high);
+
+        let hash_to_field = |i: u8, half: &[u8]| {
+            let hash = Blake2b512::new()
+                .chain_update(b"decaf377-from-uniform-bytes")
+                .chain_update([i])
+                .chain_update(half)
+                .finalize();
+            Fq::from_le_bytes_mod_order(&hash)
+        };
+        assert_eq!(
+            Element::from_uniform_bytes(&bytes),
+            Element::hash_to_curve(&hash_to_field(0, &low), &hash_to_field(1, &high))
+        );
+    }
+}
+
+#[test]
+fn random_elements_are_valid_and_distinct() {
+    use rand_core::OsRng;
+
+    let a = Element::random_from_rng(&mut OsRng);
+    let b = Element::random_from_rng(&mut OsRng);
+    assert_ne!(a, b);
+    assert_ne!(a, Element::IDENTITY);
+    for point in [a, b] {
+        assert_eq!(point.vartime_compress().vartime_decompress(), Ok(point));
+    }
+}
+
+mod serialization_modes {
+    use super::*;
+
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+    use decaf377_plus::Fq;
+
+    type AffinePoint = <Element as ark_ec::CurveGroup>::Affine;
+
+    const MODES: [(Compress, Validate); 4] = [
+        (Compress::Yes, Validate::Yes),
+        (Compress::Yes, Validate::No),
+        (Compress::No, Validate::Yes),
+        (Compress::No, Validate::No),
+    ];
+
+    fn round_trip<T>(value: &T, compress: Compress, validate: Validate) -> T
+    where
+        T: CanonicalSerialize + CanonicalDeserialize,
+    {
+        let mut bytes = Vec::new();
+        value.serialize_with_mode(&mut bytes, compress).unwrap();
+        assert_eq!(bytes.len(), value.serialized_size(compress));
+        T::deserialize_with_mode(&bytes[..], compress, validate).unwrap()
+    }
+
+    #[test]
+    fn decoding_errors_are_kept_as_the_source() {
+        use decaf377_plus::EncodingError;
+
+        fn source(err: ark_serialize::SerializationError) -> Option<EncodingError> {
+            match err {
+                ark_serialize::SerializationError::IoError(err) => err
+                    .get_ref()
+                    .and_then(|inner| inner.downcast_ref::<EncodingError>())
+                    .copied(),
+                _ => None,
+            }
+        }
+
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        for validate in [Validate::Yes, Validate::No] {
+            let err = Element::deserialize_with_mode(&bytes[..], Compress::Yes, validate);
+            assert_eq!(source(err.unwrap_err()), Some(EncodingError::NotASquare));
+            let err = AffinePoint::deserialize_with_mode(&bytes[..], Compress::Yes, validate);
+            assert_eq!(source(err.unwrap_err()), Some(EncodingError::NotASquare));
+        }
+    }
+
+    /// Serializes the affine coordinates `(x, y)` as in the uncompressed format.
+    fn uncompressed_bytes(x: Fq, y: Fq) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        x.serialize_uncompressed(&mut bytes).unwrap();
+        y.serialize_uncompressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn uncompressed_points_are_validated() {
+        // Not on the curve.
+        let bytes = uncompressed_bytes(Fq::ONE, Fq::ONE);
+        assert!(Element::deserialize_uncompressed(&bytes[..]).is_err());
+        assert!(Element::deserialize_uncompressed_unchecked(&bytes[..]).is_ok());
+
+        // On the curve, but a point of order 4, which is not the
+        // representative of any element.
+        let i = ark_ff::Field::sqrt(&-Fq::ONE).unwrap();
+        let bytes = uncompressed_bytes(i, Fq::ZERO);
+        assert!(Element::deserialize_uncompressed(&bytes[..]).is_err());
+        assert!(AffinePoint::deserialize_uncompressed(&bytes[..]).is_err());
+
+        // Non-canonical coordinates are rejected in every mode.
+        let mut bytes = uncompressed_bytes(Fq::ZERO, Fq::ONE);
+        bytes[..32].copy_from_slice(&[0xff; 32]);
+        assert!(Element::deserialize_uncompressed_unchecked(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn uncompressed_vectors_of_elements_round_trip() {
+        let points = vec![
+            Element::IDENTITY,
+            Element::GENERATOR,
+            Element::GENERATOR.double(),
+        ];
+        for (compress, validate) in MODES {
+            assert_eq!(round_trip(&points, compress, validate), points);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn element_round_trips_in_every_mode(bytes: [u8; 32]) {
+            let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+            for (compress, validate) in MODES {
+                prop_assert_eq!(round_trip(&point, compress, validate), point);
+            }
+        }
+
+        #[test]
+        fn affine_point_round_trips_in_every_mode(bytes: [u8; 32]) {
+            let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+            let affine = AffinePoint::from(point);
+            for (compress, validate) in MODES {
+                let result = round_trip(&affine, compress, validate);
+                prop_assert_eq!(Element::from(result), point);
+            }
+        }
+
+        #[test]
+        fn encoding_round_trips_in_every_mode(bytes: [u8; 32]) {
+            let encoding = Encoding(bytes);
+            for (compress, validate) in MODES {
+                prop_assert_eq!(round_trip(&encoding, compress, validate), encoding);
+            }
+        }
+    }
+}
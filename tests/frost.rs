@@ -0,0 +1,415 @@
+use std::collections::BTreeMap;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::{OsRng, RngCore, SeedableRng};
+use serde_json::{Value, json};
+use signature::Verifier;
+
+use decaf377_plus::frost::{
+    self, Identifier, KeyPackage, PublicKeyPackage, SigningPackage, SigningShare, keys, round1,
+    round2,
+};
+use decaf377_plus::{Element, Fr, FrostError};
+
+const VECTORS: &str = include_str!("test_vectors/frost.json");
+
+fn id(n: u16) -> Identifier {
+    Identifier::try_from(n).unwrap()
+}
+
+/// Runs both rounds of signing with the given signers, returning the
+/// signing package and the signature shares.
+fn sign_with(
+    key_packages: &BTreeMap<Identifier, KeyPackage>,
+    signers: &[u16],
+    msg: &[u8],
+) -> (SigningPackage, BTreeMap<Identifier, round2::SignatureShare>) {
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for &n in signers {
+        let (signer_nonces, signer_commitments) =
+            round1::commit(key_packages[&id(n)].signing_share(), &mut OsRng);
+        nonces.insert(id(n), signer_nonces);
+        commitments.insert(id(n), signer_commitments);
+    }
+
+    let signing_package = SigningPackage::new(commitments, msg);
+    let shares = nonces
+        .into_iter()
+        .map(|(identifier, signer_nonces)| {
+            let share =
+                round2::sign(&signing_package, signer_nonces, &key_packages[&identifier]).unwrap();
+            (identifier, share)
+        })
+        .collect();
+    (signing_package, shares)
+}
+
+#[test]
+fn any_threshold_of_signers_can_sign() {
+    let (key_packages, pubkeys) = keys::generate_with_dealer(5, 3, &mut OsRng).unwrap();
+    let msg = b"threshold message";
+
+    for signers in [&[1, 2, 3][..], &[1, 3, 5], &[2, 4, 5], &[1, 2, 3, 4, 5]] {
+        let (signing_package, shares) = sign_with(&key_packages, signers, msg);
+        for (identifier, share) in &shares {
+            frost::verify_signature_share(
+                identifier,
+                &pubkeys.verifying_shares()[identifier],
+                share,
+                &signing_package,
+                pubkeys.verifying_key(),
+            )
+            .unwrap();
+        }
+
+        let sig = frost::aggregate(&signing_package, &shares, &pubkeys).unwrap();
+        assert!(pubkeys.verifying_key().verify(msg, &sig).is_ok());
+        assert!(Verifier::verify(pubkeys.verifying_key(), msg, &sig).is_ok());
+        assert!(pubkeys.verifying_key().verify(b"other", &sig).is_err());
+    }
+}
+
+#[test]
+fn too_few_signers_cannot_sign() {
+    let (key_packages, _) = keys::generate_with_dealer(3, 2, &mut OsRng).unwrap();
+    let (nonces, commitments) = round1::commit(key_packages[&id(1)].signing_share(), &mut OsRng);
+    let signing_package = SigningPackage::new(BTreeMap::from([(id(1), commitments)]), b"msg");
+    assert_eq!(
+        round2::sign(&signing_package, nonces, &key_packages[&id(1)]),
+        Err(FrostError::IncorrectNumberOfCommitments)
+    );
+}
+
+#[test]
+fn mismatched_nonces_are_rejected() {
+    let (key_packages, _) = keys::generate_with_dealer(3, 2, &mut OsRng).unwrap();
+    let share = key_packages[&id(1)].signing_share();
+    let (_, commitments_1) = round1::commit(share, &mut OsRng);
+    let (_, commitments_2) = round1::commit(key_packages[&id(2)].signing_share(), &mut OsRng);
+    let signing_package = SigningPackage::new(
+        BTreeMap::from([(id(1), commitments_1), (id(2), commitments_2)]),
+        b"msg",
+    );
+
+    let (other_nonces, _) = round1::commit(share, &mut OsRng);
+    assert_eq!(
+        round2::sign(&signing_package, other_nonces, &key_packages[&id(1)]),
+        Err(FrostError::IncorrectCommitment)
+    );
+
+    let (nonces, _) = round1::commit(key_packages[&id(3)].signing_share(), &mut OsRng);
+    assert_eq!(
+        round2::sign(&signing_package, nonces, &key_packages[&id(3)]),
+        Err(FrostError::MissingCommitment)
+    );
+}
+
+#[test]
+fn invalid_share_is_attributed() {
+    let (key_packages, pubkeys) = keys::generate_with_dealer(4, 3, &mut OsRng).unwrap();
+    let (signing_package, mut shares) = sign_with(&key_packages, &[1, 2, 4], b"msg");
+
+    let bad = round2::SignatureShare::try_from(Fr::from(7u8).to_bytes()).unwrap();
+    shares.insert(id(2), bad);
+    assert_eq!(
+        frost::aggregate(&signing_package, &shares, &pubkeys),
+        Err(FrostError::InvalidSignatureShare { culprit: id(2) })
+    );
+    assert_eq!(
+        frost::verify_signature_share(
+            &id(2),
+            &pubkeys.verifying_shares()[&id(2)],
+            &bad,
+            &signing_package,
+            pubkeys.verifying_key(),
+        ),
+        Err(FrostError::InvalidSignatureShare { culprit: id(2) })
+    );
+
+    shares.remove(&id(2));
+    assert_eq!(
+        frost::aggregate(&signing_package, &shares, &pubkeys),
+        Err(FrostError::UnknownIdentifier)
+    );
+}
+
+#[test]
+fn identity_commitments_are_rejected() {
+    let (key_packages, pubkeys) = keys::generate_with_dealer(2, 2, &mut OsRng).unwrap();
+    let (nonces, commitments) = round1::commit(key_packages[&id(1)].signing_share(), &mut OsRng);
+    let identity = round1::SigningCommitments::new(Element::IDENTITY, Element::GENERATOR);
+    let signing_package = SigningPackage::new(
+        BTreeMap::from([(id(1), commitments), (id(2), identity)]),
+        b"msg",
+    );
+    assert_eq!(
+        round2::sign(&signing_package, nonces, &key_packages[&id(1)]),
+        Err(FrostError::IdentityCommitment)
+    );
+    assert_eq!(
+        frost::aggregate(&signing_package, &BTreeMap::new(), &pubkeys),
+        Err(FrostError::UnknownIdentifier)
+    );
+}
+
+#[test]
+fn split_checks_parameters() {
+    let secret = Fr::from(42u8);
+    let coefficients = [Fr::from(1u8), Fr::from(2u8)];
+    assert_eq!(
+        keys::split(&secret, &coefficients[..0], 3, 1).map(|_| ()),
+        Err(FrostError::InvalidMinSigners)
+    );
+    assert_eq!(
+        keys::split(&secret, &coefficients[..1], 1, 2).map(|_| ()),
+        Err(FrostError::InvalidMaxSigners)
+    );
+    assert_eq!(
+        keys::split(&secret, &coefficients, 3, 2).map(|_| ()),
+        Err(FrostError::InvalidCoefficients)
+    );
+
+    // f(x) = 42 + x + 2x^2
+    let (key_packages, pubkeys) = keys::split(&secret, &coefficients, 4, 3).unwrap();
+    assert_eq!(key_packages.len(), 4);
+    assert_eq!(
+        key_packages[&id(3)].signing_share(),
+        &SigningShare::from(Fr::from(63u8))
+    );
+    assert_eq!(
+        pubkeys.verifying_key().to_bytes(),
+        (Element::GENERATOR * secret).vartime_compress().0
+    );
+}
+
+#[test]
+fn identifiers_are_ordered_as_integers() {
+    assert!(id(1) < id(2));
+    assert!(id(255) < id(256));
+    assert!(id(256) < id(0x1ff));
+    assert_eq!(
+        Identifier::new(Fr::ZERO),
+        Err(FrostError::InvalidZeroIdentifier)
+    );
+    assert_eq!(
+        Identifier::try_from(0u16),
+        Err(FrostError::InvalidZeroIdentifier)
+    );
+}
+
+#[test]
+fn messages_round_trip_through_bytes() {
+    let (key_packages, _) = keys::generate_with_dealer(3, 2, &mut OsRng).unwrap();
+    let (_, commitments) = round1::commit(key_packages[&id(1)].signing_share(), &mut OsRng);
+    assert_eq!(
+        round1::SigningCommitments::try_from(commitments.to_bytes()),
+        Ok(commitments)
+    );
+
+    let (signing_package, shares) = sign_with(&key_packages, &[1, 2], b"msg");
+    let share = shares[&id(1)];
+    assert_eq!(
+        round2::SignatureShare::try_from(share.to_bytes()),
+        Ok(share)
+    );
+    assert_eq!(signing_package.message(), b"msg");
+}
+
+fn hex_bytes<const N: usize>(value: &Value) -> [u8; N] {
+    hex::decode(value.as_str().unwrap())
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+fn hex_scalar(value: &Value) -> Fr {
+    Fr::from_bytes_checked(&hex_bytes(value)).unwrap()
+}
+
+#[test]
+fn test_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    let config = &vectors["config"];
+    let inputs = &vectors["inputs"];
+    let max_signers = config["MAX_PARTICIPANTS"].as_u64().unwrap() as u16;
+    let min_signers = config["MIN_PARTICIPANTS"].as_u64().unwrap() as u16;
+    let msg = hex::decode(inputs["message"].as_str().unwrap()).unwrap();
+
+    let secret = hex_scalar(&inputs["group_secret_key"]);
+    let coefficients: Vec<Fr> = inputs["share_polynomial_coefficients"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(hex_scalar)
+        .collect();
+    let (key_packages, pubkeys) =
+        keys::split(&secret, &coefficients, max_signers, min_signers).unwrap();
+    assert_eq!(
+        hex::encode(pubkeys.verifying_key().to_bytes()),
+        inputs["group_public_key"].as_str().unwrap()
+    );
+    for share in inputs["participant_shares"].as_array().unwrap() {
+        let identifier = id(share["identifier"].as_u64().unwrap() as u16);
+        assert_eq!(
+            hex::encode(key_packages[&identifier].signing_share().to_bytes()),
+            share["participant_share"].as_str().unwrap()
+        );
+    }
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    let round_one = vectors["round_one_outputs"]["outputs"].as_array().unwrap();
+    for output in round_one {
+        let identifier = id(output["identifier"].as_u64().unwrap() as u16);
+        let signer_nonces = round1::SigningNonces::from_randomness(
+            &hex_bytes(&output["hiding_nonce_randomness"]),
+            &hex_bytes(&output["binding_nonce_randomness"]),
+            key_packages[&identifier].signing_share(),
+        );
+        let signer_commitments = *signer_nonces.commitments();
+        assert_eq!(
+            hex::encode(&signer_commitments.to_bytes()[..32]),
+            output["hiding_nonce_commitment"].as_str().unwrap()
+        );
+        assert_eq!(
+            hex::encode(&signer_commitments.to_bytes()[32..]),
+            output["binding_nonce_commitment"].as_str().unwrap()
+        );
+        nonces.insert(identifier, signer_nonces);
+        commitments.insert(identifier, signer_commitments);
+    }
+
+    let signing_package = SigningPackage::new(commitments, &msg);
+    let binding_factors = signing_package.binding_factors(pubkeys.verifying_key());
+    for output in round_one {
+        let identifier = id(output["identifier"].as_u64().unwrap() as u16);
+        assert_eq!(
+            hex::encode(binding_factors[&identifier].to_bytes()),
+            output["binding_factor"].as_str().unwrap()
+        );
+    }
+
+    let mut shares = BTreeMap::new();
+    for output in vectors["round_two_outputs"]["outputs"].as_array().unwrap() {
+        let identifier = id(output["identifier"].as_u64().unwrap() as u16);
+        let signer_nonces = nonces.remove(&identifier).unwrap();
+        let share =
+            round2::sign(&signing_package, signer_nonces, &key_packages[&identifier]).unwrap();
+        assert_eq!(
+            hex::encode(share.to_bytes()),
+            output["sig_share"].as_str().unwrap()
+        );
+        shares.insert(identifier, share);
+    }
+
+    let sig = frost::aggregate(&signing_package, &shares, &pubkeys).unwrap();
+    assert_eq!(
+        hex::encode(sig.to_bytes()),
+        vectors["final_output"]["sig"].as_str().unwrap()
+    );
+    assert!(pubkeys.verifying_key().verify(&msg, &sig).is_ok());
+}
+
+/// Writes `tests/test_vectors/frost.json`, deriving every random input from
+/// a fixed seed.
+#[ignore]
+#[test]
+fn generate_test_vectors() {
+    let mut rng = ChaCha20Rng::from_seed([0x77; 32]);
+    let (max_signers, min_signers) = (3u16, 2u16);
+    let participants = [1u16, 3];
+    let msg = b"test";
+
+    let secret = Fr::rand(&mut rng);
+    let coefficients: Vec<Fr> = (1..min_signers).map(|_| Fr::rand(&mut rng)).collect();
+    let (key_packages, pubkeys): (_, PublicKeyPackage) =
+        keys::split(&secret, &coefficients, max_signers, min_signers).unwrap();
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    let mut randomness = BTreeMap::new();
+    for &n in &participants {
+        let mut hiding = [0u8; 32];
+        let mut binding = [0u8; 32];
+        rng.fill_bytes(&mut hiding);
+        rng.fill_bytes(&mut binding);
+        let signer_nonces = round1::SigningNonces::from_randomness(
+            &hiding,
+            &binding,
+            key_packages[&id(n)].signing_share(),
+        );
+        commitments.insert(id(n), *signer_nonces.commitments());
+        nonces.insert(id(n), signer_nonces);
+        randomness.insert(n, (hiding, binding));
+    }
+
+    let signing_package = SigningPackage::new(commitments.clone(), msg);
+    let binding_factors = signing_package.binding_factors(pubkeys.verifying_key());
+    let mut shares = BTreeMap::new();
+    for (identifier, signer_nonces) in nonces {
+        let share =
+            round2::sign(&signing_package, signer_nonces, &key_packages[&identifier]).unwrap();
+        shares.insert(identifier, share);
+    }
+    let sig = frost::aggregate(&signing_package, &shares, &pubkeys).unwrap();
+
+    let vectors = json!({
+        "config": {
+            "MAX_PARTICIPANTS": max_signers,
+            "MIN_PARTICIPANTS": min_signers,
+            "NUM_PARTICIPANTS": participants.len(),
+            "name": "FROST(decaf377, BLAKE2b-512)",
+            "group": "decaf377",
+            "hash": "BLAKE2b-512",
+        },
+        "inputs": {
+            "participant_list": participants,
+            "group_secret_key": hex::encode(secret.to_bytes()),
+            "group_public_key": hex::encode(pubkeys.verifying_key().to_bytes()),
+            "message": hex::encode(msg),
+            "share_polynomial_coefficients": coefficients
+                .iter()
+                .map(|c| hex::encode(c.to_bytes()))
+                .collect::<Vec<_>>(),
+            "participant_shares": (1..=max_signers)
+                .map(|n| json!({
+                    "identifier": n,
+                    "participant_share": hex::encode(key_packages[&id(n)].signing_share().to_bytes()),
+                }))
+                .collect::<Vec<_>>(),
+        },
+        "round_one_outputs": {
+            "outputs": participants
+                .iter()
+                .map(|&n| {
+                    let bytes = commitments[&id(n)].to_bytes();
+                    json!({
+                        "identifier": n,
+                        "hiding_nonce_randomness": hex::encode(randomness[&n].0),
+                        "binding_nonce_randomness": hex::encode(randomness[&n].1),
+                        "hiding_nonce_commitment": hex::encode(&bytes[..32]),
+                        "binding_nonce_commitment": hex::encode(&bytes[32..]),
+                        "binding_factor": hex::encode(binding_factors[&id(n)].to_bytes()),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        },
+        "round_two_outputs": {
+            "outputs": participants
+                .iter()
+                .map(|&n| json!({
+                    "identifier": n,
+                    "sig_share": hex::encode(shares[&id(n)].to_bytes()),
+                }))
+                .collect::<Vec<_>>(),
+        },
+        "final_output": {
+            "sig": hex::encode(sig.to_bytes()),
+        },
+    });
+
+    let mut out = serde_json::to_string_pretty(&vectors).unwrap();
+    out.push('\n');
+    std::fs::write("tests/test_vectors/frost.json", out).expect("can write test vectors");
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use rand_core::OsRng;
+
+use decaf377_plus::FrostError;
+use decaf377_plus::frost::{
+    self, Identifier, KeyPackage, PublicKeyPackage, SigningPackage, round1, round2,
+};
+
+use common::{LIMIT, THRESHOLD, new_participants, run_dkg_rounds};
+
+/// Runs a key generation among `LIMIT` participants with identifiers `1` to
+/// `LIMIT`, any `THRESHOLD` of whom can sign.
+fn run_dkg() -> (BTreeMap<Identifier, KeyPackage>, PublicKeyPackage) {
+    let participants = run_dkg_rounds();
+    let pubkeys = PublicKeyPackage::from_participant(&participants[0]).unwrap();
+    let mut key_packages = BTreeMap::new();
+    for participant in &participants {
+        assert_eq!(
+            PublicKeyPackage::from_participant(participant).unwrap(),
+            pubkeys
+        );
+        assert_eq!(
+            pubkeys.verifying_key().to_bytes(),
+            participant.get_public_key().unwrap().vartime_compress().0
+        );
+        let key_package = KeyPackage::from_participant(participant).unwrap();
+        key_packages.insert(*key_package.identifier(), key_package);
+    }
+    (key_packages, pubkeys)
+}
+
+#[test]
+fn dkg_keys_match() {
+    let (key_packages, pubkeys) = run_dkg();
+    assert_eq!(key_packages.len(), LIMIT);
+    assert!(key_packages.keys().eq(pubkeys.verifying_shares().keys()));
+
+    for (identifier, key_package) in &key_packages {
+        assert_eq!(usize::from(key_package.min_signers()), THRESHOLD);
+        assert_eq!(key_package.verifying_key(), pubkeys.verifying_key());
+        assert_eq!(
+            key_package.verifying_share(),
+            &pubkeys.verifying_shares()[identifier]
+        );
+        assert_eq!(
+            *key_package.verifying_share(),
+            key_package.signing_share().verifying_share()
+        );
+    }
+}
+
+#[test]
+fn any_threshold_of_dkg_participants_can_sign() {
+    let (key_packages, pubkeys) = run_dkg();
+    let msg = b"distributed message";
+
+    for signers in [[1u16, 2, 3], [5, 2, 4], [1, 4, 5]] {
+        let mut nonces = BTreeMap::new();
+        let mut commitments = BTreeMap::new();
+        for n in signers {
+            let identifier = Identifier::try_from(n).unwrap();
+            let (signer_nonces, signer_commitments) =
+                round1::commit(key_packages[&identifier].signing_share(), &mut OsRng);
+            nonces.insert(identifier, signer_nonces);
+            commitments.insert(identifier, signer_commitments);
+        }
+
+        let signing_package = SigningPackage::new(commitments, msg);
+        let shares = nonces
+            .into_iter()
+            .map(|(identifier, signer_nonces)| {
+                let share =
+                    round2::sign(&signing_package, signer_nonces, &key_packages[&identifier])
+                        .unwrap();
+                (identifier, share)
+            })
+            .collect();
+
+        let sig = frost::aggregate(&signing_package, &shares, &pubkeys).unwrap();
+        assert!(pubkeys.verifying_key().verify(msg, &sig).is_ok());
+    }
+}
+
+#[test]
+fn incomplete_dkg_is_rejected() {
+    let participants = new_participants();
+    assert_eq!(
+        KeyPackage::from_participant(&participants[0]),
+        Err(FrostError::IncompleteKeyGeneration)
+    );
+    assert_eq!(
+        PublicKeyPackage::from_participant(&participants[0]),
+        Err(FrostError::IncompleteKeyGeneration)
+    );
+}
@@ -0,0 +1,116 @@
+use serde_json::Value;
+
+use decaf377_plus::Element;
+
+/// Test vectors for derived generators, which pin down the construction so
+/// that protocols built on it keep the same generators.
+const VECTORS: &str = include_str!("test_vectors/generators.json");
+
+const DOMAIN: &[u8] = b"decaf377-derive-generator-test";
+
+const LABELS: [&[u8]; 4] = [b"", b"a", b"blinding", &[0xff; 64]];
+
+/// The number of generators in the `derive_generators` test vector.
+#[cfg(feature = "alloc")]
+const N: usize = 4;
+
+#[test]
+fn derive_generator_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    let domain = hex::decode(vectors["domain"].as_str().unwrap()).unwrap();
+    assert_eq!(domain, DOMAIN);
+
+    let generators = vectors["derive_generator"].as_array().unwrap();
+    assert_eq!(generators.len(), LABELS.len());
+    for (vector, label) in generators.iter().zip(LABELS) {
+        assert_eq!(vector["label"], hex::encode(label));
+        let point = Element::derive_generator(&domain, label);
+        assert_eq!(
+            hex::encode(point.vartime_compress().0),
+            vector["P"].as_str().unwrap(),
+            "label = {label:?}"
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn derive_generators_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    let expected: Vec<&str> = vectors["derive_generators"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|point| point.as_str().unwrap())
+        .collect();
+
+    let points: Vec<String> = Element::derive_generators(DOMAIN, N)
+        .iter()
+        .map(|point| hex::encode(point.vartime_compress().0))
+        .collect();
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn generators_are_distinct_and_not_identity() {
+    let mut points: Vec<Element> = LABELS
+        .iter()
+        .map(|label| Element::derive_generator(DOMAIN, label))
+        .collect();
+    points.push(Element::derive_generator(b"another-domain", b"a"));
+    points.push(Element::GENERATOR);
+
+    for (i, p) in points.iter().enumerate() {
+        assert!(!p.is_identity());
+        for q in &points[i + 1..] {
+            assert_ne!(p, q);
+        }
+    }
+}
+
+#[test]
+fn domain_and_label_are_not_ambiguous() {
+    // Without the length prefixes, these would hash the same input.
+    assert_ne!(
+        Element::derive_generator(b"ab", b"c"),
+        Element::derive_generator(b"a", b"bc")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn derive_generators_is_prefix_stable_and_labelled_by_index() {
+    let short = Element::derive_generators(DOMAIN, 2);
+    let long = Element::derive_generators(DOMAIN, 5);
+    assert_eq!(short[..], long[..2]);
+    assert!(Element::derive_generators(DOMAIN, 0).is_empty());
+
+    for (i, point) in long.iter().enumerate() {
+        let label = (i as u64).to_le_bytes();
+        assert_eq!(*point, Element::derive_generator(DOMAIN, &label));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[ignore]
+fn generate_test_vectors() {
+    let vectors = serde_json::json!({
+        "domain": hex::encode(DOMAIN),
+        "derive_generator": LABELS
+            .iter()
+            .map(|label| serde_json::json!({
+                "label": hex::encode(label),
+                "P": hex::encode(Element::derive_generator(DOMAIN, label).vartime_compress().0),
+            }))
+            .collect::<Vec<_>>(),
+        "derive_generators": Element::derive_generators(DOMAIN, N)
+            .iter()
+            .map(|point| hex::encode(point.vartime_compress().0))
+            .collect::<Vec<_>>(),
+    });
+
+    let mut out = serde_json::to_string_pretty(&vectors).unwrap();
+    out.push('\n');
+    std::fs::write("tests/test_vectors/generators.json", out).expect("can write test vectors");
+}
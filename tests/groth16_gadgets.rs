@@ -12,7 +12,7 @@ use ark_r1cs_std::{
 };
 use ark_relations::r1cs::{ConstraintSynthesizer, ToConstraintField};
 use ark_snark::SNARK;
-use decaf377::{
+use decaf377_plus::{
     Bls12_377, Element, Fq, Fr,
     r1cs::{CountConstraints, ElementVar, FqVar},
 };
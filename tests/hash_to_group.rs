@@ -0,0 +1,96 @@
+#![cfg(feature = "group")]
+
+use blake2::Blake2b512;
+use elliptic_curve::hash2curve::{ExpandMsgXmd, ExpandMsgXof};
+use sha3::Shake256;
+
+use decaf377_plus::{Element, Fq, hash_to_group};
+
+/// Test vectors for the hash-to-group suites.
+///
+/// The field elements `u` were computed independently of this crate, from
+/// the `expand_message` definitions of RFC 9380, and the points `P` by
+/// mapping them with `Element::hash_to_curve` or `Element::encode_to_curve`.
+const VECTORS: &str = include_str!("test_vectors/hash_to_group.json");
+
+fn check_suite(suite_id: &str, hash: impl Fn(&[u8], &[u8]) -> Element) {
+    let suites: serde_json::Value = serde_json::from_str(VECTORS).unwrap();
+    let suite = suites
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|suite| suite["suite"] == suite_id)
+        .expect("suite has test vectors");
+
+    let dst = suite["dst"].as_str().unwrap();
+    assert!(dst.ends_with(suite_id));
+
+    for vector in suite["vectors"].as_array().unwrap() {
+        let msg = vector["msg"].as_str().unwrap();
+        let expected = hex::decode(vector["P"].as_str().unwrap()).unwrap();
+
+        let u: Vec<Fq> = vector["u"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|u| {
+                let bytes = hex::decode(u.as_str().unwrap()).unwrap();
+                Fq::from_bytes_checked(&bytes.try_into().unwrap()).unwrap()
+            })
+            .collect();
+        let mapped = match u.as_slice() {
+            [r_1, r_2] => Element::hash_to_curve(r_1, r_2),
+            [r] => Element::encode_to_curve(r),
+            _ => panic!("unexpected number of field elements"),
+        };
+
+        let point = hash(msg.as_bytes(), dst.as_bytes());
+        assert_eq!(point, mapped, "msg = {msg:?}");
+        assert_eq!(
+            point.vartime_compress().0[..],
+            expected[..],
+            "msg = {msg:?}"
+        );
+    }
+}
+
+#[test]
+fn xmd_blake2b_512_ro_vectors() {
+    check_suite(hash_to_group::XMD_BLAKE2B_512_RO, |msg, dst| {
+        Element::hash_to_group::<ExpandMsgXmd<Blake2b512>>(msg, dst).unwrap()
+    });
+}
+
+#[test]
+fn xmd_blake2b_512_nu_vectors() {
+    check_suite(hash_to_group::XMD_BLAKE2B_512_NU, |msg, dst| {
+        Element::encode_to_group::<ExpandMsgXmd<Blake2b512>>(msg, dst).unwrap()
+    });
+}
+
+#[test]
+fn xof_shake256_ro_vectors() {
+    check_suite(hash_to_group::XOF_SHAKE256_RO, |msg, dst| {
+        Element::hash_to_group::<ExpandMsgXof<Shake256>>(msg, dst).unwrap()
+    });
+}
+
+#[test]
+fn xof_shake256_nu_vectors() {
+    check_suite(hash_to_group::XOF_SHAKE256_NU, |msg, dst| {
+        Element::encode_to_group::<ExpandMsgXof<Shake256>>(msg, dst).unwrap()
+    });
+}
+
+#[test]
+fn empty_dst_is_rejected() {
+    assert!(Element::hash_to_group::<ExpandMsgXmd<Blake2b512>>(b"msg", b"").is_err());
+    assert!(Element::encode_to_group::<ExpandMsgXof<Shake256>>(b"msg", b"").is_err());
+}
+
+#[test]
+fn distinct_dsts_give_distinct_points() {
+    let a = Element::hash_to_group::<ExpandMsgXmd<Blake2b512>>(b"msg", b"DST-A").unwrap();
+    let b = Element::hash_to_group::<ExpandMsgXmd<Blake2b512>>(b"msg", b"DST-B").unwrap();
+    assert_ne!(a, b);
+}
@@ -0,0 +1,128 @@
+use proptest::prelude::*;
+use rand_core::OsRng;
+use subtle::ConstantTimeEq;
+
+use decaf377_plus::ka::{Public, Secret};
+use decaf377_plus::{Element, EncodingError, Fq, Fr, KeyAgreementError};
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+#[test]
+fn key_agreement_is_symmetric() {
+    let alice = Secret::new(&mut OsRng);
+    let bob = Secret::new(&mut OsRng);
+
+    let alice_shared = alice.key_agreement(&bob.public()).unwrap();
+    let bob_shared = bob.key_agreement(&alice.public()).unwrap();
+    assert_eq!(alice_shared, bob_shared);
+
+    let eve = Secret::new(&mut OsRng);
+    assert_ne!(eve.key_agreement(&bob.public()).unwrap(), bob_shared);
+}
+
+#[test]
+fn diversified_key_agreement() {
+    // A recipient publishes (B_d, pk_d = ivk * B_d), and a sender derives an
+    // ephemeral key esk * B_d from the recipient's generator.
+    let b_d = Element::encode_to_curve(&Fq::from(1234u64));
+    let ivk = Secret::new(&mut OsRng);
+    let pk_d = ivk.diversified_public(&b_d);
+
+    let esk = Secret::new(&mut OsRng);
+    let epk = esk.diversified_public(&b_d);
+
+    assert_eq!(
+        esk.key_agreement(&pk_d).unwrap(),
+        ivk.key_agreement(&epk).unwrap()
+    );
+    assert_ne!(pk_d, ivk.public());
+}
+
+#[test]
+fn identity_is_rejected() {
+    let secret = Secret::new(&mut OsRng);
+    let identity = Public::try_from(Element::IDENTITY.vartime_compress().0).unwrap();
+    assert_eq!(
+        secret.key_agreement(&identity).map(|_| ()),
+        Err(KeyAgreementError::IdentitySharedSecret)
+    );
+
+    let zero = Secret::from(Fr::ZERO);
+    let public = Secret::new(&mut OsRng).public();
+    assert_eq!(
+        zero.key_agreement(&public).map(|_| ()),
+        Err(KeyAgreementError::IdentitySharedSecret)
+    );
+}
+
+#[test]
+fn keys_round_trip_through_bytes() {
+    let secret = Secret::new(&mut OsRng);
+    let public = secret.public();
+
+    let secret2 = Secret::try_from(secret.to_bytes()).unwrap();
+    assert_eq!(secret2.public(), public);
+    assert_eq!(Public::try_from(public.to_bytes()), Ok(public));
+    assert_eq!(Public::try_from(&public.to_bytes()[..]), Ok(public));
+    assert_eq!(<[u8; 32]>::from(public), public.to_bytes());
+
+    assert_eq!(
+        Public::try_from([0xff; 32]),
+        Err(EncodingError::NonZeroHighBits)
+    );
+    assert_eq!(
+        Secret::try_from(&[0u8; 31][..]).map(|_| ()),
+        Err(EncodingError::InvalidSliceLength)
+    );
+    assert_eq!(
+        Secret::try_from([0xff; 32]).map(|_| ()),
+        Err(EncodingError::NonCanonicalFieldElement)
+    );
+}
+
+#[test]
+fn shared_secrets_compare_in_constant_time() {
+    let alice = Secret::new(&mut OsRng);
+    let bob = Secret::new(&mut OsRng);
+    let shared = alice.key_agreement(&bob.public()).unwrap();
+    let same = bob.key_agreement(&alice.public()).unwrap();
+    let other = alice.key_agreement(&alice.public()).unwrap();
+
+    assert!(bool::from(shared.ct_eq(&same)));
+    assert!(!bool::from(shared.ct_eq(&other)));
+    assert_eq!(shared, same);
+    assert_ne!(shared, other);
+}
+
+#[test]
+fn debug_hides_secrets() {
+    let secret = Secret::new(&mut OsRng);
+    let shared = secret.key_agreement(&secret.public()).unwrap();
+    assert_eq!(format!("{secret:?}"), "Secret(..)");
+    assert_eq!(format!("{shared:?}"), "SharedSecret(..)");
+}
+
+proptest! {
+    #[test]
+    fn shared_secret_is_encoding_of_product(a in fr_strategy(), b in fr_strategy()) {
+        prop_assume!(a != Fr::ZERO && b != Fr::ZERO);
+        let shared = Secret::from(a).key_agreement(&Secret::from(b).public()).unwrap();
+        prop_assert_eq!(*shared.as_bytes(), (Element::GENERATOR * (a * b)).vartime_compress().0);
+    }
+
+    #[test]
+    fn public_keys_match_variable_time_multiplication(a in fr_strategy(), d in fr_strategy()) {
+        let secret = Secret::from(a);
+        prop_assert_eq!(secret.public().to_bytes(), (Element::GENERATOR * a).vartime_compress().0);
+
+        let b_d = Element::GENERATOR * d;
+        prop_assert_eq!(
+            secret.diversified_public(&b_d).to_bytes(),
+            (b_d * a).vartime_compress().0
+        );
+    }
+}
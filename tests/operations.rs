@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 
-use decaf377::{Element, Fq, Fr};
+use decaf377_plus::{
+    BasepointTable, Element, Fq, Fr, MultiscalarMulError, VartimePrecomputedMultiscalarMul,
+};
 use proptest::prelude::*;
 
 fn element_strategy() -> BoxedStrategy<Element> {
@@ -55,7 +57,220 @@ proptest! {
             Element::vartime_multiscalar_mul(
                 &[a, b, c],
                 &[P, Q, R],
-            )
+            ).unwrap()
         );
     }
 }
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn vartime_multiscalar_mul_matches_naive(
+        scalars in prop::collection::vec(fr_strategy(), 0..300),
+        seed in element_strategy(),
+    ) {
+        // Covers both the Straus and Pippenger code paths.
+        let points: Vec<Element> = (0..scalars.len())
+            .scan(seed, |P, _| {
+                *P = P.double() + Element::GENERATOR;
+                Some(*P)
+            })
+            .collect();
+        let naive: Element = scalars.iter().zip(points.iter()).map(|(a, P)| a * P).sum();
+
+        assert_eq!(
+            Element::vartime_multiscalar_mul(&scalars, &points).unwrap(),
+            naive
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn multiscalar_mul_matches_vartime(
+        scalars in prop::collection::vec(fr_strategy(), 0..16),
+        seed in element_strategy(),
+    ) {
+        let points: Vec<Element> = (0..scalars.len())
+            .scan(seed, |P, _| {
+                *P = P.double() + Element::GENERATOR;
+                Some(*P)
+            })
+            .collect();
+
+        assert_eq!(
+            Element::multiscalar_mul(&scalars, &points).unwrap(),
+            Element::vartime_multiscalar_mul(&scalars, &points).unwrap()
+        );
+    }
+}
+
+#[test]
+fn multiscalar_mul_handles_edge_scalars() {
+    let scalars = [
+        Fr::ZERO,
+        Fr::ONE,
+        -Fr::ONE,
+        Fr::from(8u8),
+        -Fr::from(8u8),
+        Fr::from(2u8),
+    ];
+    let points = [Element::GENERATOR; 6];
+    assert_eq!(
+        Element::multiscalar_mul(&scalars, &points).unwrap(),
+        Element::GENERATOR.double()
+    );
+    assert_eq!(
+        Element::multiscalar_mul(&scalars[..0], &points[..0]).unwrap(),
+        Element::IDENTITY
+    );
+}
+
+#[test]
+fn vartime_multiscalar_mul_pippenger_windows() {
+    // Exercise each of the window widths used by Pippenger's method.
+    for n in [190, 500, 800] {
+        let scalars: Vec<Fr> = (0..n as u64)
+            .map(|i| Fr::from(i) - Fr::from(n as u64))
+            .collect();
+        let points: Vec<Element> = (0..n as u64)
+            .map(|i| Element::GENERATOR * Fr::from(i + 1))
+            .collect();
+        let expected: Fr = (0..n as u64)
+            .map(|i| (Fr::from(i) - Fr::from(n as u64)) * Fr::from(i + 1))
+            .sum();
+
+        assert_eq!(
+            Element::vartime_multiscalar_mul(&scalars, &points).unwrap(),
+            Element::GENERATOR * expected
+        );
+    }
+}
+
+#[test]
+fn multiscalar_mul_rejects_length_mismatch() {
+    let result = Element::vartime_multiscalar_mul(&[Fr::ONE, Fr::ONE], &[Element::GENERATOR]);
+    assert_eq!(
+        result,
+        Err(MultiscalarMulError::LengthMismatch {
+            scalars: 2,
+            points: 1
+        })
+    );
+
+    let result = Element::multiscalar_mul(&[Fr::ONE], &[Element::GENERATOR; 2]);
+    assert_eq!(
+        result,
+        Err(MultiscalarMulError::LengthMismatch {
+            scalars: 1,
+            points: 2
+        })
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn basepoint_table_matches_scalar_mul(a in fr_strategy(), P in element_strategy()) {
+        let table = BasepointTable::create(&P);
+        assert_eq!(table.basepoint(), P);
+        assert_eq!(&table * &a, a * P);
+        assert_eq!(&a * BasepointTable::generator(), a * Element::GENERATOR);
+    }
+
+    #[test]
+    fn precomputed_multiscalar_mul_matches_naive(
+        static_scalars in prop::collection::vec(fr_strategy(), 4),
+        dynamic_scalars in prop::collection::vec(fr_strategy(), 0..4),
+        seed in element_strategy(),
+    ) {
+        let points: Vec<Element> = (0..8)
+            .scan(seed, |P, _| {
+                *P = P.double() + Element::GENERATOR;
+                Some(*P)
+            })
+            .collect();
+        let (static_points, dynamic_points) = points.split_at(4);
+        let dynamic_points = &dynamic_points[..dynamic_scalars.len()];
+
+        let precomputed = VartimePrecomputedMultiscalarMul::new(static_points);
+        assert_eq!(precomputed.len(), 4);
+
+        let static_naive: Element = static_scalars
+            .iter()
+            .zip(static_points)
+            .map(|(a, P)| a * P)
+            .sum();
+        let dynamic_naive: Element = dynamic_scalars
+            .iter()
+            .zip(dynamic_points)
+            .map(|(b, Q)| b * Q)
+            .sum();
+
+        assert_eq!(
+            precomputed.vartime_multiscalar_mul(&static_scalars).unwrap(),
+            static_naive
+        );
+        assert_eq!(
+            precomputed
+                .vartime_mixed_multiscalar_mul(&static_scalars, &dynamic_scalars, dynamic_points)
+                .unwrap(),
+            static_naive + dynamic_naive
+        );
+    }
+}
+
+#[test]
+fn basepoint_table_handles_edge_scalars() {
+    let table = BasepointTable::generator();
+    assert_eq!(&Fr::ZERO * table, Element::IDENTITY);
+    assert_eq!(&Fr::ONE * table, Element::GENERATOR);
+    assert_eq!(&(-Fr::ONE) * table, -Element::GENERATOR);
+}
+
+#[test]
+fn precomputed_multiscalar_mul_rejects_length_mismatch() {
+    let precomputed = VartimePrecomputedMultiscalarMul::new([Element::GENERATOR; 2]);
+    assert_eq!(
+        precomputed.vartime_multiscalar_mul([Fr::ONE]),
+        Err(MultiscalarMulError::LengthMismatch {
+            scalars: 1,
+            points: 2
+        })
+    );
+    assert_eq!(
+        precomputed.vartime_mixed_multiscalar_mul([Fr::ONE; 2], [Fr::ONE; 2], [Element::GENERATOR]),
+        Err(MultiscalarMulError::LengthMismatch {
+            scalars: 2,
+            points: 1
+        })
+    );
+}
+
+proptest! {
+    #[test]
+    fn double_scalar_mul_basepoint_matches_scalar_mul(
+        a in fr_strategy(),
+        A in element_strategy(),
+        b in fr_strategy(),
+    ) {
+        assert_eq!(
+            Element::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+            a * A + b * Element::GENERATOR
+        );
+    }
+}
+
+#[test]
+fn double_scalar_mul_basepoint_handles_edge_scalars() {
+    let A = Element::GENERATOR.double();
+    for a in [Fr::ZERO, Fr::ONE, -Fr::ONE] {
+        for b in [Fr::ZERO, Fr::ONE, -Fr::ONE] {
+            assert_eq!(
+                Element::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+                a * A + b * Element::GENERATOR
+            );
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use proptest::prelude::*;
+use rand_core::OsRng;
+use signature::{RandomizedSigner, Signer, Verifier};
+
+use decaf377_plus::signature::{Signature, SigningKey, VerificationKey};
+use decaf377_plus::{Element, EncodingError, Fr};
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+#[test]
+fn sign_and_verify() {
+    let sk = SigningKey::new(&mut OsRng);
+    let vk = sk.verification_key();
+    let msg = b"decaf377 signature test";
+
+    let sig = sk.sign(&mut OsRng, msg);
+    assert!(vk.verify(msg, &sig).is_ok());
+    assert!(Verifier::verify(&vk, msg, &sig).is_ok());
+
+    let sig: Signature = sk.try_sign_with_rng(&mut OsRng, msg).unwrap();
+    assert!(vk.verify(msg, &sig).is_ok());
+
+    assert!(vk.verify(b"another message", &sig).is_err());
+    let other = SigningKey::new(&mut OsRng).verification_key();
+    assert!(other.verify(msg, &sig).is_err());
+}
+
+#[test]
+fn signer_is_deterministic() {
+    let sk = SigningKey::new(&mut OsRng);
+    let msg = b"deterministic";
+
+    let sig: Signature = sk.try_sign(msg).unwrap();
+    assert_eq!(Signer::<Signature>::sign(&sk, msg), sig);
+    assert!(sk.verification_key().verify(msg, &sig).is_ok());
+
+    // Hedged signatures use fresh nonces.
+    assert_ne!(sk.sign(&mut OsRng, msg), sk.sign(&mut OsRng, msg));
+}
+
+#[test]
+fn randomized_keys_match() {
+    let sk = SigningKey::new(&mut OsRng);
+    let randomizer = Fr::rand(&mut OsRng);
+    let msg = b"randomized";
+
+    let rsk = sk.randomize(&randomizer);
+    let rvk = sk.verification_key().randomize(&randomizer);
+    assert_eq!(rsk.verification_key(), rvk);
+
+    let sig = rsk.sign(&mut OsRng, msg);
+    assert!(rvk.verify(msg, &sig).is_ok());
+    assert!(sk.verification_key().verify(msg, &sig).is_err());
+}
+
+#[test]
+fn tampered_signatures_are_rejected() {
+    let sk = SigningKey::new(&mut OsRng);
+    let vk = sk.verification_key();
+    let msg = b"tamper";
+    let bytes = sk.sign(&mut OsRng, msg).to_bytes();
+
+    let mut tampered = bytes;
+    tampered[0] ^= 2;
+    let sig = Signature::try_from(tampered).unwrap();
+    assert!(vk.verify(msg, &sig).is_err());
+
+    let mut tampered = bytes;
+    tampered[32] ^= 1;
+    let sig = Signature::try_from(tampered).unwrap();
+    assert!(vk.verify(msg, &sig).is_err());
+
+    // A non-canonical `s` is rejected when parsing.
+    let mut tampered = bytes;
+    tampered[32..].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        Signature::try_from(tampered),
+        Err(EncodingError::NonCanonicalFieldElement)
+    );
+    assert_eq!(
+        Signature::try_from(&bytes[..63]),
+        Err(EncodingError::InvalidSliceLength)
+    );
+}
+
+#[test]
+fn keys_round_trip_through_bytes() {
+    let sk = SigningKey::new(&mut OsRng);
+    let vk = sk.verification_key();
+
+    let sk2 = SigningKey::try_from(sk.to_bytes()).unwrap();
+    assert_eq!(sk2.verification_key(), vk);
+    assert_eq!(VerificationKey::try_from(vk.to_bytes()), Ok(vk));
+    assert_eq!(VerificationKey::try_from(&vk.to_bytes()[..]), Ok(vk));
+    assert_eq!(VerificationKey::from(&sk), vk);
+    assert!(VerificationKey::try_from([0xff; 32]).is_err());
+}
+
+proptest! {
+    #[test]
+    fn keys_match_variable_time_multiplication(sk in fr_strategy(), randomizer in fr_strategy()) {
+        let sk = SigningKey::from(sk);
+        let vk = sk.verification_key();
+        let point = Element::GENERATOR * Fr::from_bytes_checked(&sk.to_bytes()).unwrap();
+        prop_assert_eq!(vk.to_bytes(), point.vartime_compress().0);
+        prop_assert_eq!(
+            vk.randomize(&randomizer).to_bytes(),
+            (point + Element::GENERATOR * randomizer).vartime_compress().0
+        );
+    }
+
+    #[test]
+    fn signatures_verify(sk in fr_strategy(), msg in prop::collection::vec(any::<u8>(), 0..100)) {
+        let sk = SigningKey::from(sk);
+        let vk = sk.verification_key();
+        let sig: Signature = Signer::sign(&sk, &msg);
+        prop_assert!(vk.verify(&msg, &sig).is_ok());
+
+        let parsed = Signature::try_from(<[u8; 64]>::from(sig)).unwrap();
+        prop_assert_eq!(parsed, sig);
+        prop_assert!(vk.verify(&msg, &parsed).is_ok());
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod batch {
+    use super::*;
+
+    use decaf377_plus::signature::batch;
+
+    fn signed_batch(n: usize) -> Vec<(VerificationKey, Signature, Vec<u8>)> {
+        (0..n)
+            .map(|i| {
+                let sk = SigningKey::new(&mut OsRng);
+                let msg = format!("message {i}").into_bytes();
+                let sig = sk.sign(&mut OsRng, &msg);
+                (sk.verification_key(), sig, msg)
+            })
+            .collect()
+    }
+
+    fn verify(items: &[(VerificationKey, Signature, Vec<u8>)]) -> Result<(), usize> {
+        let mut verifier = batch::Verifier::new();
+        for (vk, sig, msg) in items {
+            verifier.queue((*vk, *sig, msg));
+        }
+        assert_eq!(verifier.len(), items.len());
+        verifier.verify(&mut OsRng).map_err(|(i, _)| i)
+    }
+
+    #[test]
+    fn valid_batches_verify() {
+        assert!(batch::Verifier::new().is_empty());
+        assert_eq!(verify(&[]), Ok(()));
+        assert_eq!(verify(&signed_batch(1)), Ok(()));
+        assert_eq!(verify(&signed_batch(32)), Ok(()));
+
+        // Many signatures under one key.
+        let sk = SigningKey::new(&mut OsRng);
+        let items: Vec<_> = (0..8u8)
+            .map(|i| (sk.verification_key(), sk.sign(&mut OsRng, &[i]), vec![i]))
+            .collect();
+        assert_eq!(verify(&items), Ok(()));
+    }
+
+    #[test]
+    fn invalid_signature_is_found() {
+        let mut items = signed_batch(16);
+        items[11].2 = b"a different message".to_vec();
+        assert_eq!(verify(&items), Err(11));
+
+        // The lowest invalid index is reported.
+        items[4].0 = items[5].0;
+        assert_eq!(verify(&items), Err(4));
+    }
+
+    #[test]
+    fn undecodable_commitment_is_found() {
+        let mut items = signed_batch(8);
+        let mut bytes = items[6].1.to_bytes();
+        bytes[..32].copy_from_slice(&[0xff; 32]);
+        items[6].1 = Signature::try_from(bytes).unwrap();
+        assert_eq!(verify(&items), Err(6));
+    }
+
+    #[test]
+    fn items_verify_individually() {
+        let items = signed_batch(2);
+        let item = batch::Item::from((items[0].0, items[0].1, &items[0].2));
+        assert!(item.verify_single().is_ok());
+        let item = batch::Item::from((items[1].0, items[0].1, &items[0].2));
+        assert!(item.verify_single().is_err());
+    }
+}
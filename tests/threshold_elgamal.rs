@@ -0,0 +1,213 @@
+mod common;
+
+use rand_core::OsRng;
+
+use decaf377_plus::elgamal::threshold::{
+    self, ChaumPedersenProof, DecryptionShare, KeyShare, ThresholdPublicKey,
+};
+use decaf377_plus::elgamal::{Ciphertext, DiscreteLogTable};
+use decaf377_plus::{ElGamalError, Element, EncodingError, Fr};
+
+use common::{THRESHOLD, run_dkg_rounds};
+
+/// Runs a key generation among `LIMIT` participants with identifiers `1` to
+/// `LIMIT`, any `THRESHOLD` of whom can decrypt.
+fn run_dkg() -> (Vec<KeyShare>, ThresholdPublicKey) {
+    let participants = run_dkg_rounds();
+    let public = ThresholdPublicKey::from_participant(&participants[0]).unwrap();
+    for participant in &participants {
+        assert_eq!(
+            ThresholdPublicKey::from_participant(participant).unwrap(),
+            public
+        );
+        assert_eq!(
+            public.public_key().to_element(),
+            participant.get_public_key().unwrap()
+        );
+    }
+    let key_shares = participants
+        .iter()
+        .map(|p| KeyShare::try_from(&p.get_secret_share().unwrap()).unwrap())
+        .collect();
+    (key_shares, public)
+}
+
+fn decryption_shares(key_shares: &[&KeyShare], ciphertext: &Ciphertext) -> Vec<DecryptionShare> {
+    key_shares
+        .iter()
+        .map(|key_share| key_share.decryption_share(ciphertext, &mut OsRng))
+        .collect()
+}
+
+#[test]
+fn any_threshold_of_participants_can_decrypt() {
+    let (key_shares, public) = run_dkg();
+    assert_eq!(public.threshold(), THRESHOLD);
+    for key_share in &key_shares {
+        assert_eq!(
+            public.verifying_share(&key_share.identifier()),
+            key_share.verifying_share()
+        );
+    }
+
+    let ciphertext = public.public_key().encrypt(Fr::from(42u8), &mut OsRng);
+    let table = DiscreteLogTable::new(100);
+    for signers in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+        let shares = decryption_shares(&signers.map(|i| &key_shares[i]), &ciphertext);
+        assert_eq!(
+            threshold::decrypt(&ciphertext, &shares, &public, &table),
+            Ok(42)
+        );
+    }
+
+    let all: Vec<&KeyShare> = key_shares.iter().collect();
+    let shares = decryption_shares(&all, &ciphertext);
+    assert_eq!(
+        threshold::combine(&ciphertext, &shares, &public),
+        Ok(Element::GENERATOR * Fr::from(42u8))
+    );
+}
+
+#[test]
+fn encrypted_tallies_decrypt_jointly() {
+    let (key_shares, public) = run_dkg();
+    let votes = [1u64, 1, 0, 1];
+    let tally: Ciphertext = votes
+        .iter()
+        .map(|vote| public.public_key().encrypt(Fr::from(*vote), &mut OsRng))
+        .sum();
+
+    let shares = decryption_shares(&[&key_shares[3], &key_shares[1], &key_shares[2]], &tally);
+    assert_eq!(
+        threshold::decrypt(&tally, &shares, &public, &DiscreteLogTable::new(4)),
+        Ok(3)
+    );
+}
+
+#[test]
+fn invalid_share_sets_are_rejected() {
+    let (key_shares, public) = run_dkg();
+    let ciphertext = public.public_key().encrypt(Fr::from(7u8), &mut OsRng);
+    let shares = decryption_shares(
+        &[&key_shares[0], &key_shares[1], &key_shares[2]],
+        &ciphertext,
+    );
+
+    assert_eq!(
+        threshold::combine(&ciphertext, &shares[..2], &public),
+        Err(ElGamalError::TooFewDecryptionShares)
+    );
+    assert_eq!(
+        threshold::combine(&ciphertext, &[shares[0], shares[1], shares[0]], &public),
+        Err(ElGamalError::DuplicateDecryptionShare)
+    );
+
+    // A share for a different ciphertext is caught by its proof.
+    let other = public.public_key().encrypt(Fr::from(7u8), &mut OsRng);
+    let mixed = [
+        shares[0],
+        key_shares[1].decryption_share(&other, &mut OsRng),
+        shares[2],
+    ];
+    assert_eq!(
+        threshold::combine(&ciphertext, &mixed, &public),
+        Err(ElGamalError::InvalidDecryptionShare {
+            culprit: key_shares[1].identifier()
+        })
+    );
+}
+
+#[test]
+fn proofs_bind_the_share() {
+    let (key_shares, public) = run_dkg();
+    let ciphertext = public.public_key().encrypt(Fr::from(1u8), &mut OsRng);
+    let share = key_shares[0].decryption_share(&ciphertext, &mut OsRng);
+    let verifying_share = public.verifying_share(&share.identifier());
+    assert_eq!(share.verify(&ciphertext, &verifying_share), Ok(()));
+
+    // The share is checked against the verifying share of its participant.
+    let culprit = Err(ElGamalError::InvalidDecryptionShare {
+        culprit: share.identifier(),
+    });
+    assert_eq!(
+        share.verify(&ciphertext, &key_shares[1].verifying_share()),
+        culprit
+    );
+
+    // Replacing the share or its identifier invalidates the proof.
+    let mut bytes = share.to_bytes();
+    bytes[32..64].copy_from_slice(&Element::GENERATOR.vartime_compress().0);
+    let forged = DecryptionShare::try_from(bytes).unwrap();
+    assert_eq!(forged.verify(&ciphertext, &verifying_share), culprit);
+
+    let mut bytes = share.to_bytes();
+    bytes[..32].copy_from_slice(&key_shares[1].identifier().to_bytes());
+    let forged = DecryptionShare::try_from(bytes).unwrap();
+    assert!(
+        forged
+            .verify(&ciphertext, &public.verifying_share(&forged.identifier()))
+            .is_err()
+    );
+}
+
+#[test]
+fn shares_round_trip_through_encoding() {
+    let (key_shares, public) = run_dkg();
+    let ciphertext = public.public_key().encrypt(Fr::from(3u8), &mut OsRng);
+    let share = key_shares[2].decryption_share(&ciphertext, &mut OsRng);
+
+    let bytes = share.to_bytes();
+    assert_eq!(DecryptionShare::try_from(bytes), Ok(share));
+    assert_eq!(DecryptionShare::try_from(&bytes[..]), Ok(share));
+    assert_eq!(
+        DecryptionShare::try_from(&bytes[..127]),
+        Err(EncodingError::InvalidSliceLength)
+    );
+    assert_eq!(
+        ChaumPedersenProof::try_from(share.proof().to_bytes()),
+        Ok(*share.proof())
+    );
+    assert!(ChaumPedersenProof::try_from([0xff; 64]).is_err());
+}
+
+#[test]
+fn key_shares_need_nonzero_identifiers() {
+    assert_eq!(
+        KeyShare::new(Fr::ZERO, Fr::ONE).unwrap_err(),
+        ElGamalError::InvalidZeroIdentifier
+    );
+    let key_share = KeyShare::new(Fr::from(9u8), Fr::from(1234u64)).unwrap();
+    assert_eq!(key_share.identifier(), Fr::from(9u8));
+    assert!(!format!("{key_share:?}").contains("1234"));
+
+    assert_eq!(
+        ThresholdPublicKey::new(Vec::new()),
+        Err(ElGamalError::IncompleteKeyGeneration)
+    );
+}
+
+#[test]
+fn dealt_shares_decrypt() {
+    // A polynomial f(x) = sk + a_1 * x, shared without a key generation.
+    let (sk, a_1) = (Fr::from(1000u64), Fr::from(77u64));
+    let public =
+        ThresholdPublicKey::new(vec![Element::GENERATOR * sk, Element::GENERATOR * a_1]).unwrap();
+    let key_shares: Vec<KeyShare> = (1..=3u64)
+        .map(|x| KeyShare::new(Fr::from(x), sk + a_1 * Fr::from(x)).unwrap())
+        .collect();
+
+    let ciphertext = public.public_key().encrypt(Fr::from(9u8), &mut OsRng);
+    let shares = decryption_shares(&[&key_shares[2], &key_shares[0]], &ciphertext);
+    for (share, x) in shares.iter().zip([3u64, 1]) {
+        let secret = sk + a_1 * Fr::from(x);
+        assert_eq!(share.share(), ciphertext.c1 * secret);
+        assert_eq!(
+            key_shares[x as usize - 1].verifying_share(),
+            Element::GENERATOR * secret
+        );
+    }
+    assert_eq!(
+        threshold::decrypt(&ciphertext, &shares, &public, &DiscreteLogTable::new(10)),
+        Ok(9)
+    );
+}